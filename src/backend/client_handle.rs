@@ -1,52 +1,105 @@
-use anyhow::{Result, anyhow};
+use anyhow::{Context, Result, anyhow};
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
     str::FromStr,
     sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, SystemTime},
 };
+use tokio::task::AbortHandle;
 
-use super::Wallet;
-use crate::backend::{FederationConfig, FederationIdKey, FederationIdKeyPrefix};
+use super::passphrase::{self, UnlockedSeed};
+use super::profiles::profile_dir;
+use super::{
+    EcashPreview, ExportFilter, ExportFormat, FederationInfo, HistoryEntry, OperationStatus,
+    Wallet, export,
+};
+use crate::backend::{
+    AbandonedOperation, AbandonedOperationKey, AbandonedOperationKeyPrefix, EncryptedSeed,
+    EncryptedSeedKey, FederationConfig, FederationIdKey, FederationIdKeyPrefix, LastBackup,
+    LastBackupKey, LastBackupKeyPrefix, OperationLabel, OperationLabelKey, OperationLabelKeyPrefix,
+    PreferredGateway, PreferredGatewayKey, SeedBackedUp, SeedBackedUpKey,
+};
 use fedimint_bip39::{Bip39RootSecretStrategy, Mnemonic};
-use fedimint_client::{Client, ClientHandleArc, RootSecret, secret::RootSecretStrategy};
+use fedimint_client::{
+    Client, ClientHandleArc, RootSecret, db::EncodedClientSecretKey, secret::RootSecretStrategy,
+};
 use fedimint_core::{
+    Amount,
     config::FederationId,
+    core::OperationId,
     db::{Database, IDatabaseTransactionOpsCoreTyped},
     invite_code::InviteCode,
+    secp256k1::PublicKey,
 };
 use fedimint_cursed_redb::MemAndRedb;
+use fedimint_mint_client::OOBNotes;
 use futures::StreamExt;
 use rand::thread_rng;
 
+/// How long to wait for aborted tasks to drop the clients they hold
+const TASK_STOP_CHECKS: usize = 50;
+const TASK_STOP_INTERVAL: Duration = Duration::from_millis(20);
+
 // TODO: look into anyhow
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ClientHandle {
     pub clients: Arc<Mutex<BTreeMap<FederationId, ClientHandleArc>>>,
+    /// Background tasks holding on to a client, stopped when its federation is left
+    tasks: Arc<Mutex<BTreeMap<FederationId, Vec<AbortHandle>>>>,
+    /// Background tasks not tied to a federation, stopped when the profile is closed
+    background: Arc<Mutex<Vec<AbortHandle>>>,
+    /// Operations a background task follows for the History screen, so reloads don't add more
+    followed: Arc<Mutex<BTreeSet<OperationId>>>,
+    /// The decrypted seed, if it is encrypted and the user entered the passphrase
+    unlocked: Arc<Mutex<Option<UnlockedSeed>>>,
+    /// The open profile's directory
+    dir: PathBuf,
     db: Database,
 }
 
+/// What would be lost by leaving a federation right now
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeaveCheck {
+    pub federation_id: FederationId,
+    pub name: Option<String>,
+    pub balance: Amount,
+    /// Operations that haven't settled, e.g. unclaimed ecash or an unpaid invoice
+    pub pending: usize,
+}
+
+impl LeaveCheck {
+    /// Whether the federation can be left without stranding any funds
+    pub fn is_safe(&self) -> bool {
+        self.balance == Amount::ZERO && self.pending == 0
+    }
+}
+
 impl ClientHandle {
     // Should
     // load all ids from db
     // load all wallets from ids
     // set active wallet to first wallet if any
-    pub async fn new() -> Result<ClientHandle> {
-        if let Some(db_dir) = dirs::data_local_dir() {
-            let db_path = db_dir.join("tuimint/");
-            let db_file = db_path.join("tuimint.db");
-
-            let _ = tokio::fs::create_dir_all(&db_path).await;
-            let cursed_db = MemAndRedb::new(db_file).await?;
-            let db = Database::new(cursed_db, Default::default());
-
-            Ok(ClientHandle {
-                clients: Arc::new(Mutex::new(BTreeMap::new())),
-                db,
-            })
-        } else {
-            Err(anyhow!("Failed to initialize wallets db"))
-        }
+    pub async fn new(profile: &str) -> Result<ClientHandle> {
+        let dir = profile_dir(profile)?;
+        let db_file = dir.join("tuimint.db");
+
+        let _ = tokio::fs::create_dir_all(&dir).await;
+        let cursed_db = MemAndRedb::new(db_file)
+            .await
+            .context("Failed to initialize wallets db")?;
+        let db = Database::new(cursed_db, Default::default());
+
+        Ok(ClientHandle {
+            clients: Arc::new(Mutex::new(BTreeMap::new())),
+            tasks: Arc::new(Mutex::new(BTreeMap::new())),
+            background: Arc::new(Mutex::new(Vec::new())),
+            followed: Arc::new(Mutex::new(BTreeSet::new())),
+            unlocked: Arc::new(Mutex::new(None)),
+            dir,
+            db,
+        })
     }
 
     pub fn get_clients(&self) -> Result<MutexGuard<'_, BTreeMap<FederationId, ClientHandleArc>>> {
@@ -68,6 +121,243 @@ impl ClientHandle {
             .ok_or(anyhow!("Failed to get client handle with id {}", id))
     }
 
+    /// Gets a wallet for a running client, set up to use the user's preferred gateway
+    pub async fn get_wallet(&self, id: FederationId) -> Result<Wallet> {
+        let wallet = Wallet::from_client(self.get_client_by_id(id)?);
+
+        Ok(wallet.with_gateway(self.preferred_gateway(id).await))
+    }
+
+    /// The federation's config, along with the invite code we joined with
+    pub async fn federation_info(&self, id: FederationId) -> Result<FederationInfo> {
+        let mut info = self.get_wallet(id).await?.federation_info().await;
+        let mut dbtx = self.db.begin_transaction_nc().await;
+
+        info.invite_code = dbtx
+            .get_value(&FederationIdKey { id })
+            .await
+            .map(|config| config.invite_code.to_string());
+
+        Ok(info)
+    }
+
+    /// Operations of every federation, newest first, along with why some federations' couldn't be read
+    pub async fn history(&self) -> Result<(Vec<HistoryEntry>, Vec<String>)> {
+        let mut history = Vec::new();
+        let mut failed = Vec::new();
+
+        // One federation's client failing to open shouldn't hide the others
+        for id in self.get_client_ids()? {
+            match self.get_wallet(id).await {
+                Ok(wallet) => history.extend(wallet.history().await),
+                Err(e) => failed.push(format!("{id}: {e}")),
+            }
+        }
+
+        let mut labels = self.labels().await;
+        let mut abandoned = self.abandoned().await;
+        for entry in &mut history {
+            entry.label = labels.remove(&entry.operation_id);
+
+            if let Some(reason) = abandoned.remove(&entry.operation_id)
+                && entry.status == OperationStatus::Pending
+            {
+                entry.status = OperationStatus::Failed(reason);
+            }
+        }
+
+        history.sort_by(|a, b| b.time.cmp(&a.time));
+
+        Ok((history, failed))
+    }
+
+    /// The history within `filter` as file contents, along with how many operations it holds
+    pub async fn export_history(
+        &self,
+        filter: &ExportFilter,
+        format: ExportFormat,
+    ) -> Result<(String, usize)> {
+        let (entries, failed) = self.history().await?;
+
+        // A partial export would pass for a complete one
+        if !failed.is_empty() {
+            return Err(anyhow!(
+                "Couldn't read the history of {}",
+                failed.join(", ")
+            ));
+        }
+
+        let entries: Vec<HistoryEntry> = entries
+            .into_iter()
+            .filter(|entry| filter.contains(entry.time))
+            .collect();
+
+        let mut names = BTreeMap::new();
+        for id in self.get_client_ids()? {
+            if let Some(name) = self.get_wallet(id).await?.federation_name().await {
+                names.insert(id, name);
+            }
+        }
+
+        Ok((export(&entries, &names, format)?, entries.len()))
+    }
+
+    pub async fn labels(&self) -> BTreeMap<OperationId, String> {
+        let mut dbtx = self.db.begin_transaction_nc().await;
+
+        dbtx.find_by_prefix(&OperationLabelKeyPrefix)
+            .await
+            .map(|(key, value)| (key.operation_id, value.label))
+            .collect::<BTreeMap<_, _>>()
+            .await
+    }
+
+    pub async fn label(&self, operation_id: OperationId) -> Option<String> {
+        let mut dbtx = self.db.begin_transaction_nc().await;
+
+        dbtx.get_value(&OperationLabelKey { operation_id })
+            .await
+            .map(|value| value.label)
+    }
+
+    /// Labels an operation, an empty label removes it
+    pub async fn set_label(&self, operation_id: OperationId, label: &str) -> Result<()> {
+        let mut dbtx = self.db.begin_transaction().await;
+        let label = label.trim();
+
+        if label.is_empty() {
+            dbtx.remove_entry(&OperationLabelKey { operation_id }).await;
+        } else {
+            dbtx.insert_entry(
+                &OperationLabelKey { operation_id },
+                &OperationLabel {
+                    label: label.to_string(),
+                },
+            )
+            .await;
+        }
+
+        dbtx.commit_tx_result().await?;
+
+        Ok(())
+    }
+
+    /// Operations given up on, by the reason they can't finish
+    pub async fn abandoned(&self) -> BTreeMap<OperationId, String> {
+        let mut dbtx = self.db.begin_transaction_nc().await;
+
+        dbtx.find_by_prefix(&AbandonedOperationKeyPrefix)
+            .await
+            .map(|(key, value)| (key.operation_id, value.reason))
+            .collect::<BTreeMap<_, _>>()
+            .await
+    }
+
+    /// Marks an operation that can't finish as failed, so it isn't counted as pending forever
+    pub async fn abandon(&self, operation_id: OperationId, reason: &str) -> Result<()> {
+        let mut dbtx = self.db.begin_transaction().await;
+        dbtx.insert_entry(
+            &AbandonedOperationKey { operation_id },
+            &AbandonedOperation {
+                reason: reason.to_string(),
+            },
+        )
+        .await;
+        dbtx.commit_tx_result().await?;
+
+        Ok(())
+    }
+
+    /// Parses ecash notes and works out which federation they belong to
+    pub async fn preview_ecash(&self, notes: &str) -> Result<EcashPreview> {
+        let oob_notes =
+            OOBNotes::from_str(notes.trim()).map_err(|e| anyhow!("invalid ecash notes: {e}"))?;
+        let prefix = oob_notes.federation_id_prefix();
+        let invite = oob_notes.federation_invite();
+
+        let joined = self
+            .get_client_ids()?
+            .into_iter()
+            .find(|id| id.to_prefix() == prefix);
+
+        let federation_name = match joined {
+            Some(id) => self.get_wallet(id).await?.federation_name().await,
+            None => None,
+        };
+
+        Ok(EcashPreview {
+            notes: notes.trim().to_string(),
+            amount: oob_notes.total_amount(),
+            federation_id: joined.or(invite.as_ref().map(InviteCode::federation_id)),
+            federation_prefix: prefix.to_string(),
+            federation_name,
+            joined: joined.is_some(),
+            invite_code: invite.map(|invite| invite.to_string()),
+        })
+    }
+
+    /// Backs up a federation's notes to it and records when that succeeded
+    pub async fn backup(&self, id: FederationId) -> Result<SystemTime> {
+        let wallet = self.get_wallet(id).await?;
+
+        // A backup taken now would replace the good one with half-recovered notes
+        if wallet.is_recovering() {
+            return Err(anyhow!("Can't back up while the federation is recovering"));
+        }
+
+        wallet.backup().await?;
+
+        let time = SystemTime::now();
+        let mut dbtx = self.db.begin_transaction().await;
+        dbtx.insert_entry(&LastBackupKey { id }, &LastBackup { time })
+            .await;
+        dbtx.commit_tx_result().await?;
+
+        Ok(time)
+    }
+
+    /// When each federation last accepted a backup, federations never backed up are missing
+    pub async fn last_backups(&self) -> BTreeMap<FederationId, SystemTime> {
+        let mut dbtx = self.db.begin_transaction_nc().await;
+
+        dbtx.find_by_prefix(&LastBackupKeyPrefix)
+            .await
+            .map(|(key, value)| (key.id, value.time))
+            .collect::<BTreeMap<_, _>>()
+            .await
+    }
+
+    pub async fn preferred_gateway(&self, id: FederationId) -> Option<PublicKey> {
+        let mut dbtx = self.db.begin_transaction_nc().await;
+
+        dbtx.get_value(&PreferredGatewayKey { id })
+            .await
+            .map(|preferred| preferred.gateway_id)
+    }
+
+    /// Pins a gateway for a federation, or goes back to automatic selection with `None`
+    pub async fn set_preferred_gateway(
+        &self,
+        id: FederationId,
+        gateway_id: Option<PublicKey>,
+    ) -> Result<()> {
+        let mut dbtx = self.db.begin_transaction().await;
+
+        if let Some(gateway_id) = gateway_id {
+            dbtx.insert_entry(
+                &PreferredGatewayKey { id },
+                &PreferredGateway { gateway_id },
+            )
+            .await;
+        } else {
+            dbtx.remove_entry(&PreferredGatewayKey { id }).await;
+        }
+
+        dbtx.commit_tx_result().await?;
+
+        Ok(())
+    }
+
     pub async fn load_configs(&mut self) -> Result<()> {
         let mut dbtx = self.db.begin_transaction_nc().await;
         let configs = dbtx
@@ -83,7 +373,7 @@ impl ClientHandle {
 
         for config in &configs {
             let id = config.invite_code.federation_id();
-            if let Ok(wallet) = Wallet::from_opened(id, secret.clone()).await {
+            if let Ok(wallet) = Wallet::from_opened(&self.dir, id, secret.clone()).await {
                 self.get_clients()?.insert(id, wallet.client);
             }
         }
@@ -91,11 +381,25 @@ impl ClientHandle {
         Ok(())
     }
 
-    pub async fn join(&mut self, invite_code: &str) -> Result<()> {
+    /// Looks up the federation behind an invite code so the user can check it before joining
+    pub async fn preview(&self, invite_code: &str) -> Result<FederationInfo> {
+        let invite = InviteCode::from_str(invite_code.trim())?;
+
+        if self.get_clients()?.contains_key(&invite.federation_id()) {
+            return Err(anyhow!("Already joined this federation"));
+        }
+
+        let mut info = Wallet::preview(&invite).await?;
+        info.invite_code = Some(invite.to_string());
+
+        Ok(info)
+    }
+
+    /// Joins a federation the user has previewed and remembers it for the next start
+    pub async fn join(&mut self, invite_code: &str) -> Result<FederationId> {
         let secret = self.mnemonic_secret().await?;
-        let invite = InviteCode::from_str(invite_code)?;
-        let wallet = Wallet::from_joined(&invite, secret).await?;
-        let invite_code = InviteCode::from_str(invite_code)?;
+        let invite_code = InviteCode::from_str(invite_code.trim())?;
+        let wallet = Wallet::from_joined(&self.dir, &invite_code, secret).await?;
         let config = FederationConfig { invite_code };
         let id = config.invite_code.federation_id();
         let mut dbtx = self.db.begin_transaction().await;
@@ -103,12 +407,155 @@ impl ClientHandle {
         dbtx.insert_entry(&FederationIdKey { id }, &config).await;
         dbtx.commit_tx_result().await?;
 
-        self.get_clients()?
-            .insert(config.invite_code.federation_id(), wallet.client);
+        self.get_clients()?.insert(id, wallet.client);
+
+        Ok(id)
+    }
 
-        println!("{}", id.clone());
+    /// Joins a federation with the restored seed and starts recovering its ecash
+    pub async fn recover(&mut self, invite_code: &str) -> Result<FederationId> {
+        let secret = self.mnemonic_secret().await?;
+        let invite_code = InviteCode::from_str(invite_code.trim())?;
+        let id = invite_code.federation_id();
 
-        Ok(())
+        if self.get_clients()?.contains_key(&id) {
+            return Err(anyhow!("Already joined this federation"));
+        }
+
+        let wallet = Wallet::from_recovered(&self.dir, &invite_code, secret).await?;
+        let config = FederationConfig { invite_code };
+        let mut dbtx = self.db.begin_transaction().await;
+
+        dbtx.insert_entry(&FederationIdKey { id }, &config).await;
+        dbtx.commit_tx_result().await?;
+
+        self.get_clients()?.insert(id, wallet.client);
+
+        Ok(id)
+    }
+
+    /// Registers a background task that uses a federation's client, so leaving can stop it
+    pub fn track_task(&self, id: FederationId, task: AbortHandle) {
+        if let Ok(mut tasks) = self.tasks.lock() {
+            tasks.entry(id).or_default().push(task);
+        }
+    }
+
+    /// Registers a background task that isn't tied to one federation, e.g. the health monitor
+    pub fn track_background(&self, task: AbortHandle) {
+        if let Ok(mut background) = self.background.lock() {
+            background.push(task);
+        }
+    }
+
+    /// Claims an operation for following, `false` if another task already follows it
+    pub fn start_following(&self, operation_id: OperationId) -> bool {
+        self.followed
+            .lock()
+            .is_ok_and(|mut followed| followed.insert(operation_id))
+    }
+
+    pub fn stop_following(&self, operation_id: OperationId) {
+        if let Ok(mut followed) = self.followed.lock() {
+            followed.remove(&operation_id);
+        }
+    }
+
+    /// Stops every background task and client, before another profile is opened
+    pub async fn shutdown(&self) {
+        let mut stopping = Vec::new();
+
+        if let Ok(mut background) = self.background.lock() {
+            stopping.append(&mut background);
+        }
+
+        if let Ok(mut tasks) = self.tasks.lock() {
+            stopping.extend(std::mem::take(&mut *tasks).into_values().flatten());
+        }
+
+        stop_tasks(&stopping).await;
+
+        let clients = match self.get_clients() {
+            Ok(mut clients) => std::mem::take(&mut *clients),
+            Err(_) => return,
+        };
+
+        // Whatever still holds on to a client shuts it down once it lets go
+        for client in clients.into_values() {
+            if let Some(client) = Arc::into_inner(client) {
+                client.shutdown().await;
+            }
+        }
+    }
+
+    /// Checks whether leaving a federation would strand funds
+    pub async fn leave_check(&self, id: FederationId) -> Result<LeaveCheck> {
+        let mut wallet = self.get_wallet(id).await?;
+        let abandoned = self.abandoned().await;
+        let pending = wallet
+            .history()
+            .await
+            .iter()
+            .filter(|entry| entry.status == OperationStatus::Pending)
+            .filter(|entry| !abandoned.contains_key(&entry.operation_id))
+            .count();
+
+        Ok(LeaveCheck {
+            federation_id: id,
+            name: wallet.federation_name().await,
+            balance: wallet.balance().await?,
+            pending,
+        })
+    }
+
+    /// Stops a federation's client and forgets it, returning where its database was archived
+    ///
+    /// Refuses while funds or operations are left in the federation, unless `force` is set, and
+    /// while something still uses the client after its tasks were stopped
+    pub async fn leave(&self, id: FederationId, force: bool) -> Result<PathBuf> {
+        let check = self.leave_check(id).await?;
+
+        if !force && !check.is_safe() {
+            return Err(anyhow!(
+                "Federation still holds funds or pending operations"
+            ));
+        }
+
+        if let Some(tasks) = self
+            .tasks
+            .lock()
+            .ok()
+            .and_then(|mut tasks| tasks.remove(&id))
+        {
+            stop_tasks(&tasks).await;
+        }
+
+        let client = self
+            .get_clients()?
+            .remove(&id)
+            .ok_or(anyhow!("Failed to get client handle with id {}", id))?;
+
+        // Archiving the database while something still uses the client would corrupt it
+        let client = match Arc::try_unwrap(client) {
+            Ok(client) => client,
+            Err(client) => {
+                self.get_clients()?.insert(id, client);
+
+                return Err(anyhow!(
+                    "The federation's client is still in use, try leaving again in a moment"
+                ));
+            }
+        };
+
+        client.shutdown().await;
+
+        let mut dbtx = self.db.begin_transaction().await;
+        dbtx.remove_entry(&FederationIdKey { id }).await;
+        dbtx.remove_entry(&PreferredGatewayKey { id }).await;
+        dbtx.remove_entry(&LastBackupKey { id }).await;
+        dbtx.commit_tx_result().await?;
+
+        Wallet::archive_database(&self.dir, id).await
     }
 
     async fn mnemonic_secret(&self) -> Result<RootSecret> {
@@ -132,15 +579,176 @@ impl ClientHandle {
         Ok(words)
     }
 
-    pub async fn load_or_generate_mnemonic(&self) -> Result<Mnemonic> {
-        Ok(
-            if let Ok(entropy) = Client::load_decodable_client_secret::<Vec<u8>>(&self.db).await {
-                Mnemonic::from_entropy(&entropy)?
-            } else {
-                let mnemonic = Bip39RootSecretStrategy::<12>::random(&mut thread_rng());
-                Client::store_encodable_client_secret(&self.db, mnemonic.to_entropy()).await?;
-                mnemonic
+    /// Whether the user confirmed they wrote the seed down
+    pub async fn seed_backed_up(&self) -> bool {
+        let mut dbtx = self.db.begin_transaction_nc().await;
+
+        dbtx.get_value(&SeedBackedUpKey).await.is_some()
+    }
+
+    pub async fn confirm_seed_backup(&self) -> Result<()> {
+        let mut dbtx = self.db.begin_transaction().await;
+        dbtx.insert_entry(
+            &SeedBackedUpKey,
+            &SeedBackedUp {
+                time: SystemTime::now(),
             },
         )
+        .await;
+
+        dbtx.commit_tx_result().await
+    }
+
+    /// Replaces the generated seed with an existing 12 or 24 word mnemonic
+    ///
+    /// Only allowed before joining anything, clients already joined are tied to the old seed.
+    /// If the replaced seed had a passphrase, `passphrase` must match it and the restored seed
+    /// is encrypted with it too
+    pub async fn restore_mnemonic(&self, words: &str, passphrase: &str) -> Result<()> {
+        let mnemonic = Mnemonic::parse_normalized(&words.trim().to_lowercase())
+            .map_err(|e| anyhow!("Invalid mnemonic: {e}"))?;
+
+        if ![12, 24].contains(&mnemonic.word_count()) {
+            return Err(anyhow!("Mnemonic must have 12 or 24 words"));
+        }
+
+        // Federations that failed to open aren't loaded but are still tied to the old seed
+        if self.has_joined().await {
+            return Err(anyhow!(
+                "Leave all federations before restoring a different seed"
+            ));
+        }
+
+        let entropy = mnemonic.to_entropy();
+
+        if let Some(seed) = self.encrypted_seed().await {
+            // Checked before anything is replaced, a typo mustn't leave the seed unreadable
+            passphrase::decrypt(&seed, passphrase)?;
+            let seed = passphrase::encrypt(&entropy, passphrase)?;
+
+            let mut dbtx = self.db.begin_transaction().await;
+            dbtx.insert_entry(&EncryptedSeedKey, &seed).await;
+            dbtx.remove_entry(&EncodedClientSecretKey).await;
+            dbtx.commit_tx_result().await?;
+
+            *self.unlocked.lock().unwrap() = Some(UnlockedSeed(entropy));
+        } else {
+            let mut dbtx = self.db.begin_transaction().await;
+            dbtx.remove_entry(&EncodedClientSecretKey).await;
+            dbtx.commit_tx_result().await?;
+
+            Client::store_encodable_client_secret(&self.db, entropy).await?;
+            *self.unlocked.lock().unwrap() = None;
+        }
+
+        // The user just typed the words in, so they have them written down somewhere
+        self.confirm_seed_backup().await?;
+
+        Ok(())
+    }
+
+    /// Whether any federation was joined, whether or not its client could be opened
+    async fn has_joined(&self) -> bool {
+        let mut dbtx = self.db.begin_transaction_nc().await;
+
+        dbtx.find_by_prefix(&FederationIdKeyPrefix)
+            .await
+            .next()
+            .await
+            .is_some()
+    }
+
+    async fn encrypted_seed(&self) -> Option<EncryptedSeed> {
+        let mut dbtx = self.db.begin_transaction_nc().await;
+
+        dbtx.get_value(&EncryptedSeedKey).await
+    }
+
+    /// Whether the seed is protected by a passphrase
+    pub async fn is_encrypted(&self) -> bool {
+        self.encrypted_seed().await.is_some()
+    }
+
+    /// Decrypts the seed so clients can be opened, fails on a wrong passphrase
+    pub async fn unlock(&self, passphrase: &str) -> Result<()> {
+        let seed = self
+            .encrypted_seed()
+            .await
+            .ok_or(anyhow!("Seed isn't encrypted"))?;
+        let entropy = passphrase::decrypt(&seed, passphrase)?;
+
+        *self.unlocked.lock().unwrap() = Some(UnlockedSeed(entropy));
+
+        Ok(())
+    }
+
+    /// Encrypts the seed with `new`, or stores it in plaintext again if `new` is empty
+    ///
+    /// Setting the first passphrase migrates the plaintext secret, `current` is only
+    /// checked once the seed is encrypted
+    pub async fn set_passphrase(&self, current: &str, new: &str) -> Result<()> {
+        let encrypted = self.encrypted_seed().await;
+        let entropy = match &encrypted {
+            Some(seed) => passphrase::decrypt(seed, current)?,
+            None => self.load_or_generate_mnemonic().await?.to_entropy(),
+        };
+
+        if new.is_empty() {
+            if encrypted.is_some() {
+                // Plaintext first, a crash in between leaves both and the plaintext wins
+                Client::store_encodable_client_secret(&self.db, entropy.clone()).await?;
+
+                let mut dbtx = self.db.begin_transaction().await;
+                dbtx.remove_entry(&EncryptedSeedKey).await;
+                dbtx.commit_tx_result().await?;
+            }
+        } else {
+            let seed = passphrase::encrypt(&entropy, new)?;
+
+            // redb doesn't scrub freed pages, the Passphrase screen warns the plaintext may remain
+            let mut dbtx = self.db.begin_transaction().await;
+            dbtx.insert_entry(&EncryptedSeedKey, &seed).await;
+            dbtx.remove_entry(&EncodedClientSecretKey).await;
+            dbtx.commit_tx_result().await?;
+        }
+
+        *self.unlocked.lock().unwrap() = Some(UnlockedSeed(entropy));
+
+        Ok(())
+    }
+
+    pub async fn load_or_generate_mnemonic(&self) -> Result<Mnemonic> {
+        if let Ok(entropy) = Client::load_decodable_client_secret::<Vec<u8>>(&self.db).await {
+            return Ok(Mnemonic::from_entropy(&entropy)?);
+        }
+
+        if self.is_encrypted().await {
+            let unlocked = self.unlocked.lock().unwrap().clone();
+
+            return match unlocked {
+                Some(UnlockedSeed(entropy)) => Ok(Mnemonic::from_entropy(&entropy)?),
+                None => Err(anyhow!("Wallet is locked")),
+            };
+        }
+
+        let mnemonic = Bip39RootSecretStrategy::<12>::random(&mut thread_rng());
+        Client::store_encodable_client_secret(&self.db, mnemonic.to_entropy()).await?;
+
+        Ok(mnemonic)
+    }
+}
+
+/// Aborts tasks and waits a moment for them to end, they only let go of clients once polled again
+async fn stop_tasks(tasks: &[AbortHandle]) {
+    for task in tasks {
+        task.abort();
+    }
+
+    for _ in 0..TASK_STOP_CHECKS {
+        if tasks.iter().all(|task| task.is_finished()) {
+            break;
+        }
+
+        tokio::time::sleep(TASK_STOP_INTERVAL).await;
     }
 }
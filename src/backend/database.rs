@@ -1,13 +1,22 @@
 use fedimint_core::config::FederationId;
+use fedimint_core::core::OperationId;
 use fedimint_core::encoding::{Decodable, Encodable};
 use fedimint_core::invite_code::InviteCode;
+use fedimint_core::secp256k1::PublicKey;
 use fedimint_core::{impl_db_lookup, impl_db_record};
 use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
 
 #[repr(u8)]
 #[derive(Clone, Debug)]
 pub enum DbKeyPrefix {
     FederationConfig = 0x04,
+    PreferredGateway = 0x05,
+    OperationLabel = 0x06,
+    LastBackup = 0x07,
+    SeedBackedUp = 0x08,
+    EncryptedSeed = 0x09,
+    AbandonedOperation = 0x0A,
 }
 
 impl std::fmt::Display for DbKeyPrefix {
@@ -35,3 +44,132 @@ impl_db_record!(
     db_prefix = DbKeyPrefix::FederationConfig,
 );
 impl_db_lookup!(key = FederationIdKey, query_prefix = FederationIdKeyPrefix);
+
+#[derive(Debug, Clone, Encodable, Decodable, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct PreferredGatewayKey {
+    pub id: FederationId,
+}
+
+#[derive(Debug, Encodable, Decodable)]
+pub struct PreferredGatewayKeyPrefix;
+
+#[derive(Debug, Clone, Eq, PartialEq, Encodable, Decodable)]
+pub struct PreferredGateway {
+    pub gateway_id: PublicKey,
+}
+
+impl_db_record!(
+    key = PreferredGatewayKey,
+    value = PreferredGateway,
+    db_prefix = DbKeyPrefix::PreferredGateway,
+);
+impl_db_lookup!(
+    key = PreferredGatewayKey,
+    query_prefix = PreferredGatewayKeyPrefix
+);
+
+#[derive(Debug, Clone, Encodable, Decodable, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct OperationLabelKey {
+    pub operation_id: OperationId,
+}
+
+#[derive(Debug, Encodable, Decodable)]
+pub struct OperationLabelKeyPrefix;
+
+/// A note the user attached to an operation, e.g. who a payment was for
+#[derive(Debug, Clone, Eq, PartialEq, Encodable, Decodable)]
+pub struct OperationLabel {
+    pub label: String,
+}
+
+impl_db_record!(
+    key = OperationLabelKey,
+    value = OperationLabel,
+    db_prefix = DbKeyPrefix::OperationLabel,
+);
+impl_db_lookup!(
+    key = OperationLabelKey,
+    query_prefix = OperationLabelKeyPrefix
+);
+
+#[derive(Debug, Clone, Encodable, Decodable, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct LastBackupKey {
+    pub id: FederationId,
+}
+
+#[derive(Debug, Encodable, Decodable)]
+pub struct LastBackupKeyPrefix;
+
+/// When the federation last accepted an ecash backup from us
+#[derive(Debug, Clone, Eq, PartialEq, Encodable, Decodable)]
+pub struct LastBackup {
+    pub time: SystemTime,
+}
+
+impl_db_record!(
+    key = LastBackupKey,
+    value = LastBackup,
+    db_prefix = DbKeyPrefix::LastBackup,
+);
+impl_db_lookup!(key = LastBackupKey, query_prefix = LastBackupKeyPrefix);
+
+#[derive(Debug, Clone, Encodable, Decodable, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct SeedBackedUpKey;
+
+/// When the user confirmed they wrote down the seed
+#[derive(Debug, Clone, Eq, PartialEq, Encodable, Decodable)]
+pub struct SeedBackedUp {
+    pub time: SystemTime,
+}
+
+impl_db_record!(
+    key = SeedBackedUpKey,
+    value = SeedBackedUp,
+    db_prefix = DbKeyPrefix::SeedBackedUp,
+);
+
+#[derive(Debug, Clone, Encodable, Decodable, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct EncryptedSeedKey;
+
+/// The seed entropy encrypted with the user's passphrase, replaces the plaintext client secret
+#[derive(Debug, Clone, Eq, PartialEq, Encodable, Decodable)]
+pub struct EncryptedSeed {
+    /// Argon2id parameters the key was derived with, kept so they can be raised later
+    pub m_cost: u32,
+    pub t_cost: u32,
+    pub p_cost: u32,
+    pub salt: Vec<u8>,
+    pub nonce: Vec<u8>,
+    pub ciphertext: Vec<u8>,
+}
+
+impl_db_record!(
+    key = EncryptedSeedKey,
+    value = EncryptedSeed,
+    db_prefix = DbKeyPrefix::EncryptedSeed,
+);
+
+#[derive(Debug, Clone, Encodable, Decodable, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct AbandonedOperationKey {
+    pub operation_id: OperationId,
+}
+
+#[derive(Debug, Encodable, Decodable)]
+pub struct AbandonedOperationKeyPrefix;
+
+/// An operation that can't finish but that the client would wait on forever, e.g. the invoice
+/// of an LNURL-withdraw the service rejected
+#[derive(Debug, Clone, Eq, PartialEq, Encodable, Decodable)]
+pub struct AbandonedOperation {
+    pub reason: String,
+}
+
+impl_db_record!(
+    key = AbandonedOperationKey,
+    value = AbandonedOperation,
+    db_prefix = DbKeyPrefix::AbandonedOperation,
+);
+impl_db_lookup!(
+    key = AbandonedOperationKey,
+    query_prefix = AbandonedOperationKeyPrefix
+);
@@ -0,0 +1,134 @@
+use anyhow::{Result, anyhow};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const SECS_PER_DAY: u64 = 86_400;
+
+/// Converts days since the unix epoch to a (year, month, day) date
+pub fn civil_date(days: u64) -> (u64, u64, u64) {
+    // Howard Hinnant's civil_from_days, counting years from March so leap days come last
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    (year, month, day)
+}
+
+/// Converts a date to days since the unix epoch, the inverse of [`civil_date`]
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Parses a `YYYY-MM-DD` date as midnight UTC
+pub fn parse_date(date: &str) -> Result<SystemTime> {
+    let invalid = || anyhow!("invalid date {date:?}, expected YYYY-MM-DD");
+
+    let mut parts = date.trim().splitn(3, '-').map(|part| part.parse::<u64>());
+    let (Some(Ok(year)), Some(Ok(month)), Some(Ok(day))) =
+        (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid());
+    };
+
+    if year < 1970 || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return Err(invalid());
+    }
+
+    let days = days_from_civil(year, month, day);
+
+    // Catches days past the end of the month, e.g. February 30th
+    if civil_date(days) != (year, month, day) {
+        return Err(invalid());
+    }
+
+    Ok(UNIX_EPOCH + Duration::from_secs(days * SECS_PER_DAY))
+}
+
+/// Formats a point in time as an RFC 3339 UTC timestamp, e.g. `2024-05-01T13:37:05Z`
+pub fn rfc3339(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (year, month, day) = civil_date(secs / SECS_PER_DAY);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs / 3600 % 24,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn midnight(days: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(days * SECS_PER_DAY)
+    }
+
+    #[test]
+    fn civil_date_counts_from_the_epoch() {
+        assert_eq!(civil_date(0), (1970, 1, 1));
+        assert_eq!(civil_date(19_723), (2024, 1, 1));
+        assert_eq!(civil_date(19_782), (2024, 2, 29));
+        assert_eq!(civil_date(11_016), (2000, 2, 29));
+    }
+
+    #[test]
+    fn days_from_civil_inverts_civil_date() {
+        for days in 0..100_000 {
+            let (year, month, day) = civil_date(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn parse_date_accepts_leap_days() {
+        assert_eq!(parse_date("2024-02-29").unwrap(), midnight(19_782));
+        assert_eq!(parse_date("2000-02-29").unwrap(), midnight(11_016));
+        assert_eq!(parse_date(" 1970-01-01 ").unwrap(), UNIX_EPOCH);
+    }
+
+    #[test]
+    fn parse_date_rejects_days_past_the_end_of_the_month() {
+        assert!(parse_date("2024-02-30").is_err());
+        assert!(parse_date("2023-02-29").is_err());
+        assert!(parse_date("1900-02-28").is_err());
+        assert!(parse_date("2024-04-31").is_err());
+    }
+
+    #[test]
+    fn parse_date_rejects_malformed_dates() {
+        assert!(parse_date("").is_err());
+        assert!(parse_date("2024-05").is_err());
+        assert!(parse_date("2024-13-01").is_err());
+        assert!(parse_date("2024-05-00").is_err());
+        assert!(parse_date("01/05/2024").is_err());
+    }
+
+    #[test]
+    fn rfc3339_formats_utc() {
+        assert_eq!(rfc3339(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+
+        let time = midnight(19_844) + Duration::from_secs(13 * 3600 + 37 * 60 + 5);
+        assert_eq!(rfc3339(time), "2024-05-01T13:37:05Z");
+    }
+}
@@ -0,0 +1,319 @@
+use anyhow::{Context, Result, anyhow};
+use fedimint_core::{Amount, config::FederationId};
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    path::PathBuf,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use super::{ClientHandle, DEFAULT_PROFILE, HistoryEntry, list_profiles, parse_date, rfc3339};
+
+const USAGE: &str = "usage: tuimint export [--format csv|json] [--from YYYY-MM-DD] [--to YYYY-MM-DD] [--output FILE] [--profile NAME]";
+
+/// File formats the history can be exported to
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(format: &str) -> Result<Self> {
+        match format.to_lowercase().as_str() {
+            "csv" => Ok(ExportFormat::Csv),
+            "json" => Ok(ExportFormat::Json),
+            _ => Err(anyhow!(
+                "unknown export format {format:?}, expected csv or json"
+            )),
+        }
+    }
+}
+
+/// Limits an export to operations created within a range of days
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExportFilter {
+    pub from: Option<SystemTime>,
+    /// Exclusive, so a whole last day is one past its midnight
+    pub until: Option<SystemTime>,
+}
+
+impl ExportFilter {
+    /// Builds a filter from inclusive `YYYY-MM-DD` dates, empty ones leave that end open
+    pub fn from_dates(from: &str, to: &str) -> Result<Self> {
+        let from = match from.trim() {
+            "" => None,
+            date => Some(parse_date(date)?),
+        };
+        let until = match to.trim() {
+            "" => None,
+            date => Some(parse_date(date)? + Duration::from_secs(86_400)),
+        };
+
+        if let (Some(from), Some(until)) = (from, until)
+            && from >= until
+        {
+            return Err(anyhow!("the start date is after the end date"));
+        }
+
+        Ok(Self { from, until })
+    }
+
+    pub fn contains(&self, time: SystemTime) -> bool {
+        self.from.is_none_or(|from| time >= from) && self.until.is_none_or(|until| time < until)
+    }
+}
+
+/// One exported operation, flattened for spreadsheets
+#[derive(Debug, Serialize)]
+struct ExportRecord {
+    time: String,
+    unix_time: u64,
+    federation_id: String,
+    federation_name: String,
+    kind: String,
+    status: String,
+    /// Negative for money leaving the wallet
+    amount_msat: Option<i128>,
+    amount_sat: Option<f64>,
+    fee_msat: Option<u64>,
+    fee_sat: Option<f64>,
+    label: String,
+    operation_id: String,
+}
+
+impl ExportRecord {
+    fn new(entry: &HistoryEntry, names: &BTreeMap<FederationId, String>) -> Self {
+        let sign = if entry.kind.is_outgoing() { -1 } else { 1 };
+        let amount_msat = entry.amount.map(|amount| sign * i128::from(amount.msats));
+
+        Self {
+            time: rfc3339(entry.time),
+            unix_time: entry
+                .time
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            federation_id: entry.federation_id.to_string(),
+            federation_name: names.get(&entry.federation_id).cloned().unwrap_or_default(),
+            kind: entry.kind.to_string(),
+            status: entry.status.to_string(),
+            amount_msat,
+            amount_sat: amount_msat.map(|msats| msats as f64 / 1000.0),
+            fee_msat: entry.fee.map(|fee| fee.msats),
+            fee_sat: entry.fee.map(sats),
+            label: entry.label.clone().unwrap_or_default(),
+            operation_id: entry.operation_id.fmt_full().to_string(),
+        }
+    }
+}
+
+fn sats(amount: Amount) -> f64 {
+    amount.msats as f64 / 1000.0
+}
+
+/// Renders history entries, with federation names looked up in `names`
+pub fn export(
+    entries: &[HistoryEntry],
+    names: &BTreeMap<FederationId, String>,
+    format: ExportFormat,
+) -> Result<String> {
+    let records: Vec<ExportRecord> = entries
+        .iter()
+        .map(|entry| ExportRecord::new(entry, names))
+        .collect();
+
+    match format {
+        ExportFormat::Json => Ok(serde_json::to_string_pretty(&records)?),
+        ExportFormat::Csv => Ok(to_csv(&records)),
+    }
+}
+
+fn to_csv(records: &[ExportRecord]) -> String {
+    let mut csv = String::from(
+        "time,unix_time,federation_id,federation_name,kind,status,amount_msat,amount_sat,fee_msat,fee_sat,label,operation_id\n",
+    );
+
+    for record in records {
+        let fields = [
+            record.time.clone(),
+            record.unix_time.to_string(),
+            record.federation_id.clone(),
+            record.federation_name.clone(),
+            record.kind.clone(),
+            record.status.clone(),
+            optional(record.amount_msat),
+            record
+                .amount_msat
+                .map(format_msats_as_sats)
+                .unwrap_or_default(),
+            optional(record.fee_msat),
+            record
+                .fee_msat
+                .map(|msats| format_msats_as_sats(i128::from(msats)))
+                .unwrap_or_default(),
+            record.label.clone(),
+            record.operation_id.clone(),
+        ];
+
+        let line: Vec<String> = fields.iter().map(|field| escape(field)).collect();
+        csv.push_str(&line.join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
+
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+/// Exact decimal sats, CSV has no reason to go through floating point
+fn format_msats_as_sats(msats: i128) -> String {
+    let sign = if msats < 0 { "-" } else { "" };
+    let msats = msats.unsigned_abs();
+
+    format!("{sign}{}.{:03}", msats / 1000, msats % 1000)
+}
+
+/// Quotes a CSV field if it contains separators, quotes or line breaks
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Where the TUI writes exports unless told otherwise
+pub fn default_export_path(format: ExportFormat) -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(format!("tuimint-history.{}", format.extension())))
+}
+
+/// Runs `tuimint export ...` without starting the TUI, writing to stdout unless `--output` is given
+pub async fn export_command(args: &[String]) -> Result<()> {
+    let mut format = ExportFormat::default();
+    let mut from = String::new();
+    let mut to = String::new();
+    let mut output = None;
+    let mut profile = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().cloned().context(USAGE);
+
+        match arg.as_str() {
+            "--format" => format = value()?.parse()?,
+            "--from" => from = value()?,
+            "--to" => to = value()?,
+            "--output" => output = Some(PathBuf::from(value()?)),
+            "--profile" => profile = Some(value()?),
+            _ => return Err(anyhow!("unexpected argument {arg:?}\n{USAGE}")),
+        }
+    }
+
+    let filter = ExportFilter::from_dates(&from, &to)?;
+
+    // Without --profile there's only a choice to make if more than one profile exists
+    let profile = match profile {
+        Some(profile) => profile,
+        None => match list_profiles().await?.as_slice() {
+            [] => DEFAULT_PROFILE.to_string(),
+            [profile] => profile.clone(),
+            profiles => {
+                return Err(anyhow!(
+                    "pick a profile with --profile, one of: {}",
+                    profiles.join(", ")
+                ));
+            }
+        },
+    };
+
+    let mut handle = ClientHandle::new(&profile).await?;
+
+    // There's no prompt without the TUI, so an encrypted seed is unlocked from the environment
+    if handle.is_encrypted().await {
+        let passphrase = std::env::var("TUIMINT_PASSPHRASE")
+            .map_err(|_| anyhow!("seed is encrypted, set TUIMINT_PASSPHRASE to unlock it"))?;
+        handle.unlock(&passphrase).await?;
+    }
+
+    handle.load_configs().await?;
+    let (contents, count) = handle.export_history(&filter, format).await?;
+
+    match output {
+        Some(path) => {
+            tokio::fs::write(&path, contents).await?;
+            eprintln!("Exported {count} operations to {}", path.display());
+        }
+        None => print!("{contents}"),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: Duration = Duration::from_secs(86_400);
+
+    #[test]
+    fn filter_includes_the_whole_end_day() {
+        let filter = ExportFilter::from_dates("2024-05-01", "2024-05-01").unwrap();
+        let start = parse_date("2024-05-01").unwrap();
+
+        assert!(!filter.contains(start - Duration::from_secs(1)));
+        assert!(filter.contains(start));
+        assert!(filter.contains(start + DAY - Duration::from_secs(1)));
+        assert!(!filter.contains(start + DAY));
+    }
+
+    #[test]
+    fn filter_leaves_empty_ends_open() {
+        let filter = ExportFilter::from_dates("", " ").unwrap();
+        assert_eq!(filter, ExportFilter::default());
+        assert!(filter.contains(UNIX_EPOCH));
+
+        let filter = ExportFilter::from_dates("", "2024-05-01").unwrap();
+        assert!(filter.contains(UNIX_EPOCH));
+        assert!(!filter.contains(parse_date("2024-05-02").unwrap()));
+    }
+
+    #[test]
+    fn filter_rejects_reversed_dates() {
+        assert!(ExportFilter::from_dates("2024-05-02", "2024-05-01").is_err());
+        assert!(ExportFilter::from_dates("2024-02-30", "").is_err());
+    }
+
+    #[test]
+    fn escape_quotes_only_when_needed() {
+        assert_eq!(escape("coffee"), "coffee");
+        assert_eq!(escape("coffee, beans"), "\"coffee, beans\"");
+        assert_eq!(escape("the \"good\" beans"), "\"the \"\"good\"\" beans\"");
+        assert_eq!(escape("two\nlines"), "\"two\nlines\"");
+        assert_eq!(escape(""), "");
+    }
+
+    #[test]
+    fn msats_format_as_exact_sats() {
+        assert_eq!(format_msats_as_sats(0), "0.000");
+        assert_eq!(format_msats_as_sats(1_500), "1.500");
+        assert_eq!(format_msats_as_sats(21_000_001), "21000.001");
+        assert_eq!(format_msats_as_sats(-1_500), "-1.500");
+        assert_eq!(format_msats_as_sats(-1), "-0.001");
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{backend::ClientHandle, state::AppState};
+use fedimint_core::config::FederationId;
+use futures::StreamExt;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// How long the balance has to stay put before it's backed up, so a burst of operations
+/// leads to a single backup
+const BACKUP_DEBOUNCE: Duration = Duration::from_secs(30);
+
+/// Puts the backup times recorded in earlier sessions into the shared state
+pub async fn load_backups(handle: &ClientHandle, state: &Arc<Mutex<AppState>>) {
+    let last_backups = handle.last_backups().await;
+    let mut state = state.lock().unwrap();
+
+    for (federation_id, last) in last_backups {
+        state.backups.entry(federation_id).or_default().last = Some(last);
+    }
+}
+
+pub async fn backup_now(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+) {
+    backup(handle, state, federation_id).await;
+}
+
+/// Backs up a federation's ecash whenever its balance changed and then settled
+pub async fn backup_after_changes(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+) {
+    let Ok(wallet) = handle.get_wallet(federation_id).await else {
+        return;
+    };
+
+    let mut updates = wallet.subscribe_balance().await;
+
+    let task = tokio::spawn({
+        let handle = handle.clone();
+        let state = state.clone();
+
+        async move {
+            // The first update is the balance at subscription time rather than a change
+            updates.next().await;
+
+            while updates.next().await.is_some() {
+                loop {
+                    match tokio::time::timeout(BACKUP_DEBOUNCE, updates.next()).await {
+                        Ok(Some(_)) => continue,
+                        Ok(None) => return,
+                        Err(_) => break,
+                    }
+                }
+
+                // Recovery changes the balance too, but backing up mid-recovery does harm
+                if !wallet.is_recovering() {
+                    backup(&handle, &state, federation_id).await;
+                }
+            }
+        }
+    });
+    handle.track_task(federation_id, task.abort_handle());
+}
+
+async fn backup(handle: &ClientHandle, state: &Arc<Mutex<AppState>>, federation_id: FederationId) {
+    state
+        .lock()
+        .unwrap()
+        .backups
+        .entry(federation_id)
+        .or_default()
+        .running = true;
+
+    let result = handle.backup(federation_id).await;
+
+    let mut state = state.lock().unwrap();
+    let backup = state.backups.entry(federation_id).or_default();
+    backup.running = false;
+
+    match result {
+        Ok(time) => {
+            backup.last = Some(time);
+            backup.error = None;
+        }
+        Err(e) => backup.error = Some(e.to_string()),
+    }
+}
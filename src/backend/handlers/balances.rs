@@ -0,0 +1,30 @@
+use crate::{backend::ClientHandle, state::AppState};
+use fedimint_core::config::FederationId;
+use futures::StreamExt;
+use std::sync::{Arc, Mutex};
+
+/// Keeps a federation's balance in the shared state current for as long as its client runs
+pub async fn watch_balance(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+) {
+    let Ok(wallet) = handle.get_wallet(federation_id).await else {
+        return;
+    };
+
+    let mut updates = wallet.subscribe_balance().await;
+    let state = state.clone();
+
+    let task = tokio::spawn(async move {
+        while let Some(balance) = updates.next().await {
+            let mut state = state.lock().unwrap();
+
+            // Federations only show up once the first refresh has listed them
+            if let Some(federation) = state.federations.iter_mut().find(|f| f.id == federation_id) {
+                federation.balance = balance;
+            }
+        }
+    });
+    handle.track_task(federation_id, task.abort_handle());
+}
@@ -0,0 +1,88 @@
+use crate::{
+    backend::ClientHandle,
+    state::{AppState, Deposit},
+};
+use fedimint_core::{Amount, config::FederationId};
+use fedimint_wallet_client::DepositStateV2;
+use futures::StreamExt;
+use std::sync::{Arc, Mutex};
+
+pub async fn deposit_address(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+) {
+    state.lock().unwrap().deposit = Deposit::Allocating;
+
+    let result = async {
+        let mut wallet = handle.get_wallet(federation_id).await?;
+        let (operation_id, address) = wallet.deposit_address().await?;
+        let updates = wallet.subscribe_deposit(operation_id).await?;
+
+        anyhow::Ok((address.to_string(), updates))
+    }
+    .await;
+
+    let (address, mut updates) = match result {
+        Ok(result) => result,
+        Err(e) => {
+            state.lock().unwrap().deposit = Deposit::Failed(e.to_string());
+            return;
+        }
+    };
+
+    state.lock().unwrap().deposit = Deposit::WaitingForTransaction {
+        address: address.clone(),
+    };
+
+    // Deposits need several confirmations, so follow them in the background
+    let task = tokio::spawn({
+        let handle = handle.clone();
+        let state = state.clone();
+
+        async move {
+            while let Some(update) = updates.next().await {
+                let status = match update {
+                    DepositStateV2::WaitingForTransaction => continue,
+                    DepositStateV2::WaitingForConfirmation {
+                        btc_deposited,
+                        btc_out_point,
+                    } => Deposit::Seen {
+                        address: address.clone(),
+                        amount: Amount::from_sats(btc_deposited.to_sat()),
+                        txid: btc_out_point.txid.to_string(),
+                    },
+                    DepositStateV2::Confirmed {
+                        btc_deposited,
+                        btc_out_point,
+                    } => Deposit::Confirmed {
+                        address: address.clone(),
+                        amount: Amount::from_sats(btc_deposited.to_sat()),
+                        txid: btc_out_point.txid.to_string(),
+                    },
+                    DepositStateV2::Claimed {
+                        btc_deposited,
+                        btc_out_point,
+                    } => Deposit::Claimed {
+                        address: address.clone(),
+                        amount: Amount::from_sats(btc_deposited.to_sat()),
+                        txid: btc_out_point.txid.to_string(),
+                    },
+                    DepositStateV2::Failed(error) => Deposit::Failed(error),
+                };
+
+                let mut current = state.lock().unwrap();
+
+                // A newer address has replaced this one on the Deposit screen
+                if current.deposit.address() != Some(address.as_str()) {
+                    break;
+                }
+
+                current.deposit = status;
+            }
+
+            super::refresh_clients::refresh_clients(&handle, &state).await;
+        }
+    });
+    handle.track_task(federation_id, task.abort_handle());
+}
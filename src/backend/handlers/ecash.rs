@@ -0,0 +1,245 @@
+use super::refresh_clients::refresh_clients;
+use crate::{
+    backend::{ClientHandle, OutgoingSpend},
+    state::{AppState, EcashReceive, EcashSend, PendingSpend, SpendNotice},
+};
+use fedimint_core::{Amount, config::FederationId, core::OperationId};
+use fedimint_mint_client::{ReissueExternalNotesState, SpendOOBState};
+use futures::StreamExt;
+use std::{
+    sync::{Arc, Mutex},
+    time::SystemTime,
+};
+
+pub async fn spend_ecash(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+    amount: Amount,
+) {
+    state.lock().unwrap().ecash_send = EcashSend::Spending;
+
+    let result = async {
+        let mut wallet = handle.get_wallet(federation_id).await?;
+        wallet.spend_ecash(amount).await
+    }
+    .await;
+
+    match result {
+        Ok((operation_id, notes)) => {
+            let amount = notes.total_amount();
+
+            state.lock().unwrap().ecash_send = EcashSend::Spent {
+                amount,
+                notes: notes.to_string(),
+            };
+
+            let spend = OutgoingSpend {
+                operation_id,
+                amount,
+                created: SystemTime::now(),
+            };
+            track_spend(handle, state, federation_id, spend).await;
+        }
+        Err(e) => state.lock().unwrap().ecash_send = EcashSend::Failed(e.to_string()),
+    }
+
+    refresh_clients(handle, state).await;
+}
+
+/// Picks up spends left over from earlier sessions, the client reclaims them once they time out
+///
+/// Walks the whole operation log of every federation, so it runs in the background
+pub async fn sweep_outgoing_spends(handle: &ClientHandle, state: &Arc<Mutex<AppState>>) {
+    let task = tokio::spawn({
+        let handle = handle.clone();
+        let state = state.clone();
+
+        async move {
+            let Ok(ids) = handle.get_client_ids() else {
+                return;
+            };
+
+            for federation_id in ids {
+                let Ok(wallet) = handle.get_wallet(federation_id).await else {
+                    continue;
+                };
+
+                for spend in wallet.outgoing_spends().await {
+                    track_spend(&handle, &state, federation_id, spend).await;
+                }
+            }
+        }
+    });
+
+    handle.track_background(task.abort_handle());
+}
+
+pub async fn reclaim_ecash(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+    operation_id: OperationId,
+) {
+    set_reclaiming(state, operation_id, true);
+
+    let result = async {
+        let wallet = handle.get_wallet(federation_id).await?;
+        wallet.reclaim_ecash(operation_id).await
+    }
+    .await;
+
+    if let Err(e) = result {
+        set_reclaiming(state, operation_id, false);
+        state.lock().unwrap().spend_notice = Some(SpendNotice::Failed(e.to_string()));
+    }
+}
+
+fn set_reclaiming(state: &Arc<Mutex<AppState>>, operation_id: OperationId, reclaiming: bool) {
+    let mut state = state.lock().unwrap();
+
+    if let Some(spend) = state
+        .pending_spends
+        .iter_mut()
+        .find(|spend| spend.operation_id == operation_id)
+    {
+        spend.reclaiming = reclaiming;
+    }
+}
+
+/// Lists a spend as pending until the recipient redeems it or we get the notes back
+async fn track_spend(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+    spend: OutgoingSpend,
+) {
+    let result = async {
+        let wallet = handle.get_wallet(federation_id).await?;
+        wallet.subscribe_ecash_spend(spend.operation_id).await
+    }
+    .await;
+
+    let Ok(mut updates) = result else {
+        return;
+    };
+
+    {
+        let mut state = state.lock().unwrap();
+
+        if state
+            .pending_spends
+            .iter()
+            .any(|pending| pending.operation_id == spend.operation_id)
+        {
+            return;
+        }
+
+        state.pending_spends.push(PendingSpend {
+            federation_id,
+            operation_id: spend.operation_id,
+            amount: spend.amount,
+            created: spend.created,
+            reclaiming: false,
+        });
+    }
+
+    let task = tokio::spawn({
+        let handle = handle.clone();
+        let state = state.clone();
+
+        async move {
+            while let Some(update) = updates.next().await {
+                let notice = match update {
+                    SpendOOBState::Created => continue,
+                    SpendOOBState::UserCanceledProcessing => {
+                        set_reclaiming(&state, spend.operation_id, true);
+                        continue;
+                    }
+                    SpendOOBState::UserCanceledSuccess | SpendOOBState::Refunded => {
+                        SpendNotice::Reclaimed(spend.amount)
+                    }
+                    SpendOOBState::UserCanceledFailure | SpendOOBState::Success => {
+                        SpendNotice::Redeemed(spend.amount)
+                    }
+                };
+
+                let mut current = state.lock().unwrap();
+                current
+                    .pending_spends
+                    .retain(|pending| pending.operation_id != spend.operation_id);
+                current.spend_notice = Some(notice);
+                break;
+            }
+
+            refresh_clients(&handle, &state).await;
+        }
+    });
+    handle.track_task(federation_id, task.abort_handle());
+}
+
+pub async fn preview_ecash(handle: &ClientHandle, state: &Arc<Mutex<AppState>>, notes: String) {
+    state.lock().unwrap().ecash_receive = EcashReceive::Parsing;
+
+    let status = match handle.preview_ecash(&notes).await {
+        Ok(preview) => EcashReceive::Confirm(preview),
+        Err(e) => EcashReceive::Failed(e.to_string()),
+    };
+
+    state.lock().unwrap().ecash_receive = status;
+}
+
+pub async fn receive_ecash(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+    notes: String,
+) {
+    let result = async {
+        let mut wallet = handle.get_wallet(federation_id).await?;
+        let (operation_id, amount) = wallet.receive_ecash(&notes).await?;
+        let updates = wallet.subscribe_ecash_receive(operation_id).await?;
+
+        anyhow::Ok((amount, updates))
+    }
+    .await;
+
+    let (amount, mut updates) = match result {
+        Ok(result) => result,
+        Err(e) => {
+            state.lock().unwrap().ecash_receive = EcashReceive::Failed(e.to_string());
+            return;
+        }
+    };
+
+    state.lock().unwrap().ecash_receive = EcashReceive::Reissuing { amount };
+
+    // The federation needs a consensus round to sign the new notes, so follow it in the background
+    let task = tokio::spawn({
+        let handle = handle.clone();
+        let state = state.clone();
+
+        async move {
+            while let Some(update) = updates.next().await {
+                let status = match update {
+                    ReissueExternalNotesState::Created => EcashReceive::Reissuing { amount },
+                    ReissueExternalNotesState::Issuing => EcashReceive::Issuing { amount },
+                    ReissueExternalNotesState::Done => EcashReceive::Received { amount },
+                    ReissueExternalNotesState::Failed(error) => EcashReceive::Failed(error),
+                };
+
+                let mut current = state.lock().unwrap();
+
+                // The user left the screen or started over with other notes
+                if !current.ecash_receive.is_redeeming() {
+                    break;
+                }
+
+                current.ecash_receive = status;
+            }
+
+            refresh_clients(&handle, &state).await;
+        }
+    });
+    handle.track_task(federation_id, task.abort_handle());
+}
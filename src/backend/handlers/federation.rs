@@ -0,0 +1,21 @@
+use crate::{
+    backend::ClientHandle,
+    state::{AppState, FederationView},
+};
+use fedimint_core::config::FederationId;
+use std::sync::{Arc, Mutex};
+
+pub async fn load_federation(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+) {
+    state.lock().unwrap().federation = FederationView::Loading;
+
+    let federation = match handle.federation_info(federation_id).await {
+        Ok(info) => FederationView::Loaded(info),
+        Err(e) => FederationView::Failed(e.to_string()),
+    };
+
+    state.lock().unwrap().federation = federation;
+}
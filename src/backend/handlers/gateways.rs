@@ -0,0 +1,52 @@
+use crate::{
+    backend::ClientHandle,
+    state::{AppState, GatewayList},
+};
+use fedimint_core::{config::FederationId, secp256k1::PublicKey};
+use std::sync::{Arc, Mutex};
+
+pub async fn list_gateways(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+) {
+    state.lock().unwrap().gateways = GatewayList::Loading;
+
+    let result = async {
+        let wallet = handle.get_wallet(federation_id).await?;
+        wallet.list_gateways().await
+    }
+    .await;
+
+    let preferred = handle.preferred_gateway(federation_id).await;
+
+    state.lock().unwrap().gateways = match result {
+        Ok(gateways) => GatewayList::Loaded {
+            gateways,
+            preferred,
+        },
+        Err(e) => GatewayList::Failed(e.to_string()),
+    };
+}
+
+pub async fn select_gateway(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+    gateway_id: Option<PublicKey>,
+) {
+    let result = handle
+        .set_preferred_gateway(federation_id, gateway_id)
+        .await;
+
+    let mut state = state.lock().unwrap();
+
+    match result {
+        Ok(()) => {
+            if let GatewayList::Loaded { preferred, .. } = &mut state.gateways {
+                *preferred = gateway_id;
+            }
+        }
+        Err(e) => state.gateways = GatewayList::Failed(e.to_string()),
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{backend::ClientHandle, state::AppState};
+use fedimint_core::config::FederationId;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// How often the guardians of every federation are checked in the background
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Checks the guardians of every running client periodically, for as long as the profile is open
+pub async fn monitor_health(handle: &ClientHandle, state: &Arc<Mutex<AppState>>) {
+    let task = tokio::spawn({
+        let handle = handle.clone();
+        let state = state.clone();
+
+        async move {
+            loop {
+                // Listed every round so federations joined in the meantime are picked up
+                for federation_id in handle.get_client_ids().unwrap_or_default() {
+                    check(&handle, &state, federation_id).await;
+                }
+
+                tokio::time::sleep(HEALTH_CHECK_INTERVAL).await;
+            }
+        }
+    });
+
+    handle.track_background(task.abort_handle());
+}
+
+/// Checks a federation's guardians right away, without holding up other messages
+pub async fn check_health(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+) {
+    let task = tokio::spawn({
+        let handle = handle.clone();
+        let state = state.clone();
+
+        async move { check(&handle, &state, federation_id).await }
+    });
+    handle.track_task(federation_id, task.abort_handle());
+}
+
+async fn check(handle: &ClientHandle, state: &Arc<Mutex<AppState>>, federation_id: FederationId) {
+    let Ok(wallet) = handle.get_wallet(federation_id).await else {
+        return;
+    };
+
+    let health = wallet.guardian_health().await;
+
+    state
+        .lock()
+        .unwrap()
+        .guardian_health
+        .insert(federation_id, health);
+}
@@ -0,0 +1,201 @@
+use crate::{
+    backend::{ClientHandle, ExportFilter, ExportFormat, HistoryEntry, OperationStatus},
+    state::{AppState, Export, History, OperationView},
+};
+use fedimint_core::{config::FederationId, core::OperationId};
+use futures::StreamExt;
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::SystemTime,
+};
+
+pub async fn load_history(handle: &ClientHandle, state: &Arc<Mutex<AppState>>) {
+    state.lock().unwrap().history = History::Loading;
+
+    let (entries, failed) = match handle.history().await {
+        Ok(history) => history,
+        Err(e) => {
+            state.lock().unwrap().history = History::Failed(e.to_string());
+            return;
+        }
+    };
+
+    follow_pending(handle, state, &entries);
+    state.lock().unwrap().history = History::Loaded { entries, failed };
+}
+
+/// Operations that ended while nobody followed them have no outcome in the log and look
+/// pending, so their modules are asked where they ended up
+fn follow_pending(handle: &ClientHandle, state: &Arc<Mutex<AppState>>, entries: &[HistoryEntry]) {
+    let pending = entries
+        .iter()
+        .filter(|entry| entry.status == OperationStatus::Pending);
+
+    for entry in pending {
+        let (federation_id, operation_id) = (entry.federation_id, entry.operation_id);
+
+        if !handle.start_following(operation_id) {
+            continue;
+        }
+
+        let task = tokio::spawn({
+            let handle = handle.clone();
+            let state = state.clone();
+
+            async move {
+                let result = async {
+                    let wallet = handle.get_wallet(federation_id).await?;
+                    wallet.subscribe_operation(operation_id).await
+                }
+                .await;
+
+                // Finished operations replay their last state and end the stream right away
+                if let Ok(mut updates) = result {
+                    while let Some(update) = updates.next().await {
+                        let mut state = state.lock().unwrap();
+
+                        if let History::Loaded { entries, .. } = &mut state.history
+                            && let Some(listed) =
+                                entries.iter_mut().find(|e| e.operation_id == operation_id)
+                        {
+                            listed.status = update.status;
+                        }
+                    }
+                }
+
+                handle.stop_following(operation_id);
+            }
+        });
+
+        handle.track_task(federation_id, task.abort_handle());
+    }
+}
+
+pub async fn watch_operation(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+    operation_id: OperationId,
+    created: SystemTime,
+) {
+    state.lock().unwrap().operation = OperationView::Loading(operation_id);
+
+    let result = async {
+        let wallet = handle.get_wallet(federation_id).await?;
+        wallet.operation_detail(operation_id, created).await
+    }
+    .await;
+
+    let mut detail = match result {
+        Ok(detail) => detail,
+        Err(e) => {
+            state.lock().unwrap().operation = OperationView::Failed(e.to_string());
+            return;
+        }
+    };
+
+    detail.entry.label = handle.label(operation_id).await;
+
+    let finished = detail.entry.status != OperationStatus::Pending;
+    state.lock().unwrap().operation = OperationView::Loaded(detail);
+
+    if finished {
+        return;
+    }
+
+    let result = async {
+        let wallet = handle.get_wallet(federation_id).await?;
+        wallet.subscribe_operation(operation_id).await
+    }
+    .await;
+
+    let Ok(mut updates) = result else {
+        return;
+    };
+
+    // In-flight operations can take a while, so follow them in the background
+    let task = tokio::spawn({
+        let state = state.clone();
+
+        async move {
+            while let Some(update) = updates.next().await {
+                let mut current = state.lock().unwrap();
+
+                let OperationView::Loaded(detail) = &mut current.operation else {
+                    break;
+                };
+
+                // The user opened another operation in the meantime
+                if detail.entry.operation_id != operation_id {
+                    break;
+                }
+
+                detail.apply(update, SystemTime::now());
+                let entry = detail.entry.clone();
+
+                // Keep the History screen in step with what we see here
+                if let History::Loaded { entries, .. } = &mut current.history
+                    && let Some(listed) =
+                        entries.iter_mut().find(|e| e.operation_id == operation_id)
+                {
+                    *listed = entry;
+                }
+            }
+        }
+    });
+    handle.track_task(federation_id, task.abort_handle());
+}
+
+pub async fn set_label(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    operation_id: OperationId,
+    label: String,
+) {
+    if let Err(e) = handle.set_label(operation_id, &label).await {
+        state.lock().unwrap().operation = OperationView::Failed(e.to_string());
+        return;
+    }
+
+    let label = Some(label.trim().to_string()).filter(|label| !label.is_empty());
+    let mut state = state.lock().unwrap();
+
+    if let OperationView::Loaded(detail) = &mut state.operation
+        && detail.entry.operation_id == operation_id
+    {
+        detail.entry.label = label.clone();
+    }
+
+    if let History::Loaded { entries, .. } = &mut state.history
+        && let Some(entry) = entries.iter_mut().find(|e| e.operation_id == operation_id)
+    {
+        entry.label = label;
+    }
+}
+
+pub async fn export_history(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    format: ExportFormat,
+    filter: ExportFilter,
+    path: PathBuf,
+) {
+    state.lock().unwrap().export = Export::Exporting;
+
+    let result = async {
+        let (contents, count) = handle.export_history(&filter, format).await?;
+        tokio::fs::write(&path, contents).await?;
+
+        anyhow::Ok(count)
+    }
+    .await;
+
+    state.lock().unwrap().export = match result {
+        Ok(count) => Export::Done {
+            path: path.display().to_string(),
+            count,
+        },
+        Err(e) => Export::Failed(e.to_string()),
+    };
+}
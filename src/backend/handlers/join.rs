@@ -0,0 +1,60 @@
+use super::{
+    backups::backup_after_changes, balances::watch_balance, health::check_health,
+    refresh_clients::refresh_clients,
+};
+use crate::{
+    backend::ClientHandle,
+    state::{AppState, JoinFederation},
+};
+use std::sync::{Arc, Mutex};
+
+pub async fn preview_federation(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    invite_code: String,
+) {
+    state.lock().unwrap().join = JoinFederation::Previewing;
+
+    let join = match handle.preview(&invite_code).await {
+        Ok(info) => JoinFederation::Confirm(info),
+        Err(e) => JoinFederation::Failed(e.to_string()),
+    };
+
+    state.lock().unwrap().join = join;
+}
+
+pub async fn join_federation(
+    handle: &mut ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    invite_code: String,
+) {
+    let info = {
+        let mut state = state.lock().unwrap();
+
+        // Only join what the user confirmed on the Join screen
+        let JoinFederation::Confirm(info) = &state.join else {
+            return;
+        };
+
+        let info = info.clone();
+        state.join = JoinFederation::Joining(info.clone());
+        info
+    };
+
+    let federation_id = match handle.join(&invite_code).await {
+        Ok(federation_id) => federation_id,
+        Err(e) => {
+            state.lock().unwrap().join = JoinFederation::Failed(e.to_string());
+            return;
+        }
+    };
+
+    refresh_clients(handle, state).await;
+    watch_balance(handle, state, federation_id).await;
+    backup_after_changes(handle, state, federation_id).await;
+    check_health(handle, state, federation_id).await;
+
+    let mut state = state.lock().unwrap();
+    state.selected_federation = Some(federation_id);
+    state.join = JoinFederation::Joined(info);
+}
@@ -0,0 +1,57 @@
+use super::refresh_clients::refresh_clients;
+use crate::{
+    backend::ClientHandle,
+    state::{AppState, LeaveFederation},
+};
+use fedimint_core::config::FederationId;
+use std::sync::{Arc, Mutex};
+
+pub async fn check_leave(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+) {
+    state.lock().unwrap().leave = LeaveFederation::Checking;
+
+    let leave = match handle.leave_check(federation_id).await {
+        Ok(check) => LeaveFederation::Confirm(check),
+        Err(e) => LeaveFederation::Failed(e.to_string()),
+    };
+
+    state.lock().unwrap().leave = leave;
+}
+
+pub async fn leave_federation(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+    force: bool,
+) {
+    let name = {
+        let mut state = state.lock().unwrap();
+        state.leave = LeaveFederation::Leaving;
+        state
+            .selected()
+            .and_then(|federation| federation.name.clone())
+    };
+
+    let archive = match handle.leave(federation_id, force).await {
+        Ok(archive) => archive,
+        Err(e) => {
+            state.lock().unwrap().leave = LeaveFederation::Failed(e.to_string());
+            return;
+        }
+    };
+
+    refresh_clients(handle, state).await;
+
+    let mut state = state.lock().unwrap();
+    state.guardian_health.remove(&federation_id);
+    state
+        .pending_spends
+        .retain(|pending| pending.federation_id != federation_id);
+    state.leave = LeaveFederation::Left {
+        name,
+        archive: archive.display().to_string(),
+    };
+}
@@ -0,0 +1,79 @@
+use super::send_lightning;
+use crate::{
+    backend::{ClientHandle, LnurlResolver, PayRequest},
+    state::{AppState, LightningSend},
+};
+use fedimint_core::{Amount, config::FederationId};
+use std::sync::{Arc, Mutex};
+
+/// Resolves an LNURL or lightning address in the background, a slow service mustn't hold up
+/// other messages
+pub async fn fetch_pay_request(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    target: String,
+) {
+    state.lock().unwrap().ln_send = LightningSend::Decoding;
+
+    let task = tokio::spawn({
+        let state = state.clone();
+
+        async move {
+            let result = LnurlResolver::new().fetch_pay_request(&target).await;
+            let mut state = state.lock().unwrap();
+
+            // The user gave up on this one in the meantime
+            if state.ln_send != LightningSend::Decoding {
+                return;
+            }
+
+            state.ln_send = match result {
+                Ok(request) => LightningSend::EnterAmount(request),
+                Err(e) => LightningSend::Failed(e.to_string()),
+            };
+        }
+    });
+    handle.track_background(task.abort_handle());
+}
+
+/// Gets an invoice from the LNURL service, then quotes it like a pasted invoice
+pub async fn request_invoice(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+    request: PayRequest,
+    amount: Amount,
+    comment: String,
+) {
+    state.lock().unwrap().ln_send = LightningSend::Decoding;
+
+    let task = tokio::spawn({
+        let handle = handle.clone();
+        let state = state.clone();
+
+        async move {
+            let result = LnurlResolver::new()
+                .request_invoice(&request, amount, &comment)
+                .await;
+
+            // The user gave up on this one in the meantime
+            if state.lock().unwrap().ln_send != LightningSend::Decoding {
+                return;
+            }
+
+            match result {
+                Ok(invoice) => {
+                    send_lightning::quote_invoice(
+                        &handle,
+                        &state,
+                        federation_id,
+                        invoice.to_string(),
+                    )
+                    .await
+                }
+                Err(e) => state.lock().unwrap().ln_send = LightningSend::Failed(e.to_string()),
+            }
+        }
+    });
+    handle.track_task(federation_id, task.abort_handle());
+}
@@ -0,0 +1,126 @@
+use crate::{
+    backend::{ClientHandle, LnurlResolver, LnurlWithdrawRequest, OperationMeta},
+    state::{AppState, LnurlWithdraw},
+};
+use fedimint_core::{Amount, config::FederationId};
+use fedimint_ln_client::LnReceiveState;
+use futures::StreamExt;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// The service pays right after we submit, so the invoice doesn't need to live long
+const INVOICE_EXPIRY: Duration = Duration::from_secs(60 * 10);
+
+/// Fetches the withdraw request in the background, a slow service mustn't hold up other messages
+pub async fn fetch_lnurl_withdraw(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    target: String,
+) {
+    state.lock().unwrap().lnurl_withdraw = LnurlWithdraw::Fetching;
+
+    let task = tokio::spawn({
+        let state = state.clone();
+
+        async move {
+            let result = LnurlResolver::new().fetch_withdraw_request(&target).await;
+            let mut state = state.lock().unwrap();
+
+            // The user gave up on this one in the meantime
+            if state.lnurl_withdraw != LnurlWithdraw::Fetching {
+                return;
+            }
+
+            state.lnurl_withdraw = match result {
+                Ok(request) => LnurlWithdraw::Confirm(request),
+                Err(e) => LnurlWithdraw::Failed(e.to_string()),
+            };
+        }
+    });
+    handle.track_background(task.abort_handle());
+}
+
+/// Creates an invoice and hands it to the service, then waits for the service to pay it
+///
+/// Runs in the background since both the service and the payment can take a while
+pub async fn redeem(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+    request: LnurlWithdrawRequest,
+    amount: Amount,
+) {
+    state.lock().unwrap().lnurl_withdraw = LnurlWithdraw::CreatingInvoice;
+
+    if amount < request.min_withdrawable || amount > request.max_withdrawable {
+        state.lock().unwrap().lnurl_withdraw = LnurlWithdraw::Failed(format!(
+            "amount must be between {} and {} msat",
+            request.min_withdrawable.msats, request.max_withdrawable.msats
+        ));
+        return;
+    }
+
+    let task = tokio::spawn({
+        let handle = handle.clone();
+        let state = state.clone();
+
+        async move {
+            if let Err(e) = redeem_invoice(&handle, &state, federation_id, &request, amount).await {
+                state.lock().unwrap().lnurl_withdraw = LnurlWithdraw::Failed(e.to_string());
+            }
+
+            super::refresh_clients::refresh_clients(&handle, &state).await;
+        }
+    });
+    handle.track_task(federation_id, task.abort_handle());
+}
+
+async fn redeem_invoice(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+    request: &LnurlWithdrawRequest,
+    amount: Amount,
+) -> anyhow::Result<()> {
+    let mut wallet = handle.get_wallet(federation_id).await?;
+    let meta = OperationMeta {
+        lnurl: Some(request.target.clone()),
+    };
+    let (operation_id, invoice) = wallet
+        .receive_lightning_with_meta(amount, request.description.clone(), INVOICE_EXPIRY, meta)
+        .await?;
+    let mut updates = wallet.subscribe_lightning_receive(operation_id).await?;
+
+    state.lock().unwrap().lnurl_withdraw = LnurlWithdraw::Submitting;
+
+    if let Err(e) = LnurlResolver::new()
+        .submit_withdraw(request, &invoice)
+        .await
+    {
+        // Nobody is going to pay the invoice, it would otherwise count as pending for good
+        handle
+            .abandon(
+                operation_id,
+                &format!("LNURL service didn't take the invoice: {e}"),
+            )
+            .await?;
+
+        return Err(e);
+    }
+
+    state.lock().unwrap().lnurl_withdraw = LnurlWithdraw::AwaitingPayment;
+
+    while let Some(update) = updates.next().await {
+        let status = match update {
+            LnReceiveState::Claimed => LnurlWithdraw::Claimed { amount },
+            LnReceiveState::Canceled { reason } => LnurlWithdraw::Canceled(reason.to_string()),
+            _ => continue,
+        };
+
+        state.lock().unwrap().lnurl_withdraw = status;
+    }
+
+    Ok(())
+}
@@ -1,15 +1,219 @@
+mod backups;
+mod balances;
+mod deposit;
+mod ecash;
+mod federation;
+mod gateways;
+mod health;
+mod history;
+mod join;
+mod leave;
+mod lnurl_pay;
+mod lnurl_withdraw;
+mod passphrase;
+mod peg_out;
+mod profiles;
+mod receive_lightning;
 mod refresh_clients;
+mod restore;
+mod seed;
+mod send_lightning;
 
+use crate::{
+    backend::{ClientHandle, is_lnurl},
+    message::Message,
+    state::AppState,
+};
 use std::sync::{Arc, Mutex};
 use tokio::sync::mpsc::UnboundedReceiver;
-use crate::{message::Message, state::AppState};
 
 /// Handles messages sent from the UI
 /// Used for triggering asynchronous backend actions
 pub async fn handle_messages(mut rx: UnboundedReceiver<Message>, state: Arc<Mutex<AppState>>) {
+    let Some(mut profile) = profiles::wait_for_profile(&state, &mut rx).await else {
+        return;
+    };
+
+    // Switching profiles closes the open one and starts over with the next
+    while let Some(next) = run_profile(&profile, &mut rx, &state).await {
+        profile = next;
+    }
+}
+
+/// Opens a profile's wallet and handles messages until another profile is selected, or picked
+/// instead of one that failed to open
+async fn run_profile(
+    profile: &str,
+    rx: &mut UnboundedReceiver<Message>,
+    state: &Arc<Mutex<AppState>>,
+) -> Option<String> {
+    state.lock().unwrap().open_profile(profile);
+
+    let mut handle = match ClientHandle::new(profile).await {
+        Ok(handle) => handle,
+        Err(e) => return profiles::open_failed(state, rx, profile, e).await,
+    };
+
+    // An encrypted seed has to be unlocked before any client can be opened
+    if handle.is_encrypted().await {
+        passphrase::wait_for_unlock(&handle, state, rx).await;
+    }
+
+    if let Err(e) = handle.load_configs().await {
+        state.lock().unwrap().error = Some(e.to_string());
+    }
+
+    // Messages sent while locked were dropped, including the UI's initial refresh
+    refresh_clients::refresh_clients(&handle, state).await;
+
+    for federation_id in handle.get_client_ids().unwrap_or_default() {
+        balances::watch_balance(&handle, state, federation_id).await;
+        backups::backup_after_changes(&handle, state, federation_id).await;
+        restore::resume_recovery(&handle, state, federation_id).await;
+    }
+
+    backups::load_backups(&handle, state).await;
+    seed::load_seed_backup(&handle, state).await;
+    ecash::sweep_outgoing_spends(&handle, state).await;
+    health::monitor_health(&handle, state).await;
+
     while let Some(msg) = rx.recv().await {
         match msg {
-            Message::RefreshClients => refresh_clients::refresh_clients(&state).await,
+            Message::RefreshClients => refresh_clients::refresh_clients(&handle, state).await,
+            Message::ReceiveLightning {
+                federation_id,
+                amount,
+                description,
+            } => {
+                receive_lightning::receive_lightning(
+                    &handle,
+                    state,
+                    federation_id,
+                    amount,
+                    description,
+                )
+                .await
+            }
+            Message::QuoteInvoice { invoice, .. } if is_lnurl(&invoice) => {
+                lnurl_pay::fetch_pay_request(&handle, state, invoice).await
+            }
+            Message::QuoteInvoice {
+                federation_id,
+                invoice,
+            } => send_lightning::quote_invoice(&handle, state, federation_id, invoice).await,
+            Message::RequestLnurlInvoice {
+                federation_id,
+                request,
+                amount,
+                comment,
+            } => {
+                lnurl_pay::request_invoice(&handle, state, federation_id, request, amount, comment)
+                    .await
+            }
+            Message::PayInvoice {
+                federation_id,
+                quote,
+            } => send_lightning::pay_invoice(&handle, state, federation_id, quote).await,
+            Message::PreviewFederation(invite_code) => {
+                join::preview_federation(&handle, state, invite_code).await
+            }
+            Message::JoinFederation(invite_code) => {
+                join::join_federation(&mut handle, state, invite_code).await
+            }
+            Message::BackupNow(federation_id) => {
+                backups::backup_now(&handle, state, federation_id).await
+            }
+            Message::SelectProfile(next) => {
+                handle.shutdown().await;
+                return Some(next);
+            }
+            Message::CreateProfile(name) => profiles::create_profile(state, name).await,
+            Message::RenameProfile { from, to } => profiles::rename_profile(state, from, to).await,
+            Message::DeleteProfile(name) => profiles::delete_profile(state, name).await,
+            Message::Unlock(passphrase) => {
+                passphrase::unlock(&handle, state, passphrase).await;
+            }
+            Message::SetPassphrase { current, new } => {
+                passphrase::set_passphrase(&handle, state, current, new).await
+            }
+            Message::RevealSeed => seed::reveal_seed(&handle, state).await,
+            Message::ConfirmSeedBackup => seed::confirm_seed_backup(&handle, state).await,
+            Message::RestoreSeed { words, passphrase } => {
+                restore::restore_seed(&handle, state, words, passphrase).await
+            }
+            Message::RecoverFederation(invite_code) => {
+                restore::recover_federation(&mut handle, state, invite_code).await
+            }
+            Message::CheckLeave(federation_id) => {
+                leave::check_leave(&handle, state, federation_id).await
+            }
+            Message::LeaveFederation {
+                federation_id,
+                force,
+            } => leave::leave_federation(&handle, state, federation_id, force).await,
+            Message::LoadFederation(federation_id) => {
+                federation::load_federation(&handle, state, federation_id).await
+            }
+            Message::CheckHealth(federation_id) => {
+                health::check_health(&handle, state, federation_id).await
+            }
+            Message::ListGateways(federation_id) => {
+                gateways::list_gateways(&handle, state, federation_id).await
+            }
+            Message::SelectGateway {
+                federation_id,
+                gateway_id,
+            } => gateways::select_gateway(&handle, state, federation_id, gateway_id).await,
+            Message::FetchLnurlWithdraw(target) => {
+                lnurl_withdraw::fetch_lnurl_withdraw(&handle, state, target).await
+            }
+            Message::RedeemLnurlWithdraw {
+                federation_id,
+                request,
+                amount,
+            } => lnurl_withdraw::redeem(&handle, state, federation_id, request, amount).await,
+            Message::DepositAddress(federation_id) => {
+                deposit::deposit_address(&handle, state, federation_id).await
+            }
+            Message::QuoteWithdraw {
+                federation_id,
+                address,
+                amount,
+            } => peg_out::quote_withdraw(&handle, state, federation_id, address, amount).await,
+            Message::Withdraw {
+                federation_id,
+                quote,
+            } => peg_out::withdraw(&handle, state, federation_id, quote).await,
+            Message::SpendEcash {
+                federation_id,
+                amount,
+            } => ecash::spend_ecash(&handle, state, federation_id, amount).await,
+            Message::ReclaimEcash {
+                federation_id,
+                operation_id,
+            } => ecash::reclaim_ecash(&handle, state, federation_id, operation_id).await,
+            Message::LoadHistory => history::load_history(&handle, state).await,
+            Message::WatchOperation {
+                federation_id,
+                operation_id,
+                created,
+            } => {
+                history::watch_operation(&handle, state, federation_id, operation_id, created).await
+            }
+            Message::SetLabel {
+                operation_id,
+                label,
+            } => history::set_label(&handle, state, operation_id, label).await,
+            Message::ExportHistory {
+                format,
+                filter,
+                path,
+            } => history::export_history(&handle, state, format, filter, path).await,
+            Message::PreviewEcash(notes) => ecash::preview_ecash(&handle, state, notes).await,
+            Message::ReceiveEcash {
+                federation_id,
+                notes,
+            } => ecash::receive_ecash(&handle, state, federation_id, notes).await,
             // Message::RefreshWallets(client_id) => {
             //     state.wallets.clear();
             //     state.wallets.push(client_id);
@@ -17,4 +221,6 @@ pub async fn handle_messages(mut rx: UnboundedReceiver<Message>, state: Arc<Mute
             _ => {}
         }
     }
+
+    None
 }
@@ -0,0 +1,73 @@
+use crate::{
+    backend::ClientHandle,
+    message::Message,
+    state::{AppState, PassphraseChange, Screen, Unlock},
+};
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc::UnboundedReceiver;
+
+/// Keeps the passphrase prompt up until the seed is decrypted, other messages are dropped
+/// since no client can be opened before then
+pub async fn wait_for_unlock(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    rx: &mut UnboundedReceiver<Message>,
+) {
+    {
+        let mut state = state.lock().unwrap();
+        state.encrypted = true;
+        state.unlock = Unlock::Locked;
+        state.navigate(Screen::Unlock);
+    }
+
+    while let Some(msg) = rx.recv().await {
+        if let Message::Unlock(passphrase) = msg
+            && unlock(handle, state, passphrase).await
+        {
+            return;
+        }
+    }
+}
+
+/// Checks the passphrase entered at the lock screen, returns whether it was right
+pub async fn unlock(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    passphrase: String,
+) -> bool {
+    state.lock().unwrap().unlock = Unlock::Unlocking;
+
+    let result = handle.unlock(&passphrase).await;
+    let mut state = state.lock().unwrap();
+
+    match result {
+        Ok(()) => {
+            state.unlocked();
+            true
+        }
+        Err(e) => {
+            state.unlock = Unlock::Failed(e.to_string());
+            false
+        }
+    }
+}
+
+pub async fn set_passphrase(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    current: String,
+    new: String,
+) {
+    state.lock().unwrap().passphrase = PassphraseChange::Saving;
+
+    let result = handle.set_passphrase(&current, &new).await;
+    let mut state = state.lock().unwrap();
+
+    match result {
+        Ok(()) => {
+            state.encrypted = !new.is_empty();
+            state.passphrase = PassphraseChange::Saved;
+        }
+        Err(e) => state.passphrase = PassphraseChange::Failed(e.to_string()),
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    backend::{ClientHandle, WithdrawAmount, WithdrawQuote},
+    state::{AppState, PegOut},
+};
+use fedimint_core::config::FederationId;
+use fedimint_wallet_client::WithdrawState;
+use futures::StreamExt;
+use std::sync::{Arc, Mutex};
+
+pub async fn quote_withdraw(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+    address: String,
+    amount: WithdrawAmount,
+) {
+    state.lock().unwrap().peg_out = PegOut::Quoting;
+
+    let result = async {
+        let mut wallet = handle.get_wallet(federation_id).await?;
+        wallet.quote_withdraw(&address, amount).await
+    }
+    .await;
+
+    state.lock().unwrap().peg_out = match result {
+        Ok(quote) => PegOut::Confirm(quote),
+        Err(e) => PegOut::Failed(e.to_string()),
+    };
+}
+
+pub async fn withdraw(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+    quote: WithdrawQuote,
+) {
+    state.lock().unwrap().peg_out = PegOut::Withdrawing;
+
+    let result = async {
+        let mut wallet = handle.get_wallet(federation_id).await?;
+        let operation_id = wallet.withdraw(&quote).await?;
+
+        wallet.subscribe_withdraw(operation_id).await
+    }
+    .await;
+
+    let mut updates = match result {
+        Ok(updates) => updates,
+        Err(e) => {
+            state.lock().unwrap().peg_out = PegOut::Failed(e.to_string());
+            return;
+        }
+    };
+
+    // The federation has to reach consensus on the transaction, so wait in the background
+    let task = tokio::spawn({
+        let handle = handle.clone();
+        let state = state.clone();
+
+        async move {
+            while let Some(update) = updates.next().await {
+                let status = match update {
+                    WithdrawState::Created => continue,
+                    WithdrawState::Succeeded(txid) => PegOut::Succeeded {
+                        txid: txid.to_string(),
+                    },
+                    WithdrawState::Failed(error) => PegOut::Failed(error),
+                };
+
+                state.lock().unwrap().peg_out = status;
+            }
+
+            super::refresh_clients::refresh_clients(&handle, &state).await;
+        }
+    });
+    handle.track_task(federation_id, task.abort_handle());
+}
@@ -0,0 +1,141 @@
+use crate::{
+    backend::{self, DEFAULT_PROFILE},
+    message::Message,
+    state::{AppState, ProfileAction, Screen},
+};
+use anyhow::{Result, anyhow};
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc::UnboundedReceiver;
+
+/// Picks the profile to open at startup, the user is only asked if there's more than one
+pub async fn wait_for_profile(
+    state: &Arc<Mutex<AppState>>,
+    rx: &mut UnboundedReceiver<Message>,
+) -> Option<String> {
+    let profiles = match backend::list_profiles().await {
+        Ok(profiles) => profiles,
+        Err(e) => {
+            state.lock().unwrap().error = Some(e.to_string());
+            return None;
+        }
+    };
+
+    {
+        let mut state = state.lock().unwrap();
+        state.profiles = profiles.clone();
+
+        if profiles.len() <= 1 {
+            return Some(
+                profiles
+                    .into_iter()
+                    .next()
+                    .unwrap_or(DEFAULT_PROFILE.to_string()),
+            );
+        }
+    }
+
+    choose_profile(state, rx).await
+}
+
+/// Shows the picker until the user picks a profile, e.g. once the picked one failed to open
+///
+/// Profiles can be managed from the picker, other messages are dropped until one is picked
+async fn choose_profile(
+    state: &Arc<Mutex<AppState>>,
+    rx: &mut UnboundedReceiver<Message>,
+) -> Option<String> {
+    state.lock().unwrap().navigate(Screen::Profiles);
+
+    while let Some(msg) = rx.recv().await {
+        match msg {
+            Message::SelectProfile(profile) => return Some(profile),
+            Message::CreateProfile(name) => create_profile(state, name).await,
+            Message::RenameProfile { from, to } => rename_profile(state, from, to).await,
+            Message::DeleteProfile(name) => delete_profile(state, name).await,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+pub async fn create_profile(state: &Arc<Mutex<AppState>>, name: String) {
+    state.lock().unwrap().profile_action = ProfileAction::Working;
+
+    let result = backend::create_profile(&name).await;
+
+    finish(state, result.map(|()| format!("Created profile {name}"))).await;
+}
+
+pub async fn rename_profile(state: &Arc<Mutex<AppState>>, from: String, to: String) {
+    state.lock().unwrap().profile_action = ProfileAction::Working;
+
+    let result = match refuse_open(state, &from) {
+        Ok(()) => backend::rename_profile(&from, &to).await,
+        Err(e) => Err(e),
+    };
+
+    finish(state, result.map(|()| format!("Renamed {from} to {to}"))).await;
+}
+
+pub async fn delete_profile(state: &Arc<Mutex<AppState>>, name: String) {
+    state.lock().unwrap().profile_action = ProfileAction::Working;
+
+    let result = match refuse_open(state, &name) {
+        Ok(()) => backend::delete_profile(&name).await,
+        Err(e) => Err(e),
+    };
+
+    finish(
+        state,
+        result.map(|path| format!("Deleted {name}, its data was moved to {}", path.display())),
+    )
+    .await;
+}
+
+/// The open profile's databases are in use, so it can't be moved
+fn refuse_open(state: &Arc<Mutex<AppState>>, name: &str) -> Result<()> {
+    if state.lock().unwrap().profile.as_deref() == Some(name) {
+        return Err(anyhow!("Switch to another profile first, {name} is open"));
+    }
+
+    Ok(())
+}
+
+/// Reports how the action went and lists the profiles again
+async fn finish(state: &Arc<Mutex<AppState>>, result: Result<String>) {
+    let profiles = backend::list_profiles().await;
+    let mut state = state.lock().unwrap();
+
+    if let Ok(profiles) = profiles {
+        state.profiles = profiles;
+    }
+
+    state.profile_action = match result {
+        Ok(done) => ProfileAction::Done(done),
+        Err(e) => ProfileAction::Failed(e.to_string()),
+    };
+}
+
+/// Goes back to the picker after a profile failed to open, so another one can be picked
+pub async fn open_failed(
+    state: &Arc<Mutex<AppState>>,
+    rx: &mut UnboundedReceiver<Message>,
+    profile: &str,
+    error: anyhow::Error,
+) -> Option<String> {
+    let profiles = backend::list_profiles().await;
+
+    {
+        let mut state = state.lock().unwrap();
+
+        if let Ok(profiles) = profiles {
+            state.profiles = profiles;
+        }
+
+        state.profile = None;
+        state.profile_action = ProfileAction::Failed(format!("Couldn't open {profile}: {error}"));
+    }
+
+    choose_profile(state, rx).await
+}
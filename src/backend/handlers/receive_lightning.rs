@@ -0,0 +1,82 @@
+use crate::{
+    backend::ClientHandle,
+    state::{AppState, LightningReceive},
+};
+use fedimint_core::{Amount, config::FederationId};
+use fedimint_ln_client::LnReceiveState;
+use futures::StreamExt;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// How long a generated invoice stays payable
+const INVOICE_EXPIRY: Duration = Duration::from_secs(60 * 60);
+
+pub async fn receive_lightning(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+    amount: Amount,
+    description: String,
+) {
+    state.lock().unwrap().ln_receive = LightningReceive::CreatingInvoice;
+
+    let result = async {
+        let mut wallet = handle.get_wallet(federation_id).await?;
+        let (operation_id, invoice) = wallet
+            .receive_lightning(amount, description, INVOICE_EXPIRY)
+            .await?;
+        let updates = wallet.subscribe_lightning_receive(operation_id).await?;
+
+        anyhow::Ok((invoice.to_string(), updates))
+    }
+    .await;
+
+    let (invoice, mut updates) = match result {
+        Ok(result) => result,
+        Err(e) => {
+            state.lock().unwrap().ln_receive = LightningReceive::Failed(e.to_string());
+            return;
+        }
+    };
+
+    state.lock().unwrap().ln_receive = LightningReceive::AwaitingPayment {
+        invoice: invoice.clone(),
+    };
+
+    // Waiting for the payer can take a long time, so don't block other messages
+    let task = tokio::spawn({
+        let handle = handle.clone();
+        let state = state.clone();
+
+        async move {
+            while let Some(update) = updates.next().await {
+                let status = match update {
+                    LnReceiveState::Created | LnReceiveState::WaitingForPayment { .. } => continue,
+                    LnReceiveState::Funded | LnReceiveState::AwaitingFunds => {
+                        LightningReceive::Funded {
+                            invoice: invoice.clone(),
+                        }
+                    }
+                    LnReceiveState::Claimed => LightningReceive::Claimed { amount },
+                    LnReceiveState::Canceled { reason } => {
+                        LightningReceive::Canceled(reason.to_string())
+                    }
+                };
+
+                let mut current = state.lock().unwrap();
+
+                // A newer invoice has replaced this one on the Receive screen
+                if !current.ln_receive.is_for_invoice(&invoice) {
+                    break;
+                }
+
+                current.ln_receive = status;
+            }
+
+            super::refresh_clients::refresh_clients(&handle, &state).await;
+        }
+    });
+    handle.track_task(federation_id, task.abort_handle());
+}
@@ -1,6 +1,32 @@
+use crate::{
+    backend::ClientHandle,
+    state::{AppState, FederationSummary},
+};
+use fedimint_core::Amount;
 use std::sync::{Arc, Mutex};
-use crate::state::AppState;
 
-pub async fn refresh_clients(state: &Arc<Mutex<AppState>>) {
-    println!("refresh clients");
+pub async fn refresh_clients(handle: &ClientHandle, state: &Arc<Mutex<AppState>>) {
+    let mut federations = Vec::new();
+
+    for id in handle.get_client_ids().unwrap_or_default() {
+        let Ok(mut wallet) = handle.get_wallet(id).await else {
+            continue;
+        };
+
+        federations.push(FederationSummary {
+            id,
+            name: wallet.federation_name().await,
+            balance: wallet.balance().await.unwrap_or(Amount::ZERO),
+        });
+    }
+
+    let mut state = state.lock().unwrap();
+
+    // Keep the current selection if that federation is still around
+    let selected = state
+        .selected_federation
+        .filter(|id| federations.iter().any(|f| f.id == *id));
+
+    state.selected_federation = selected.or(federations.first().map(|f| f.id));
+    state.federations = federations;
 }
@@ -0,0 +1,165 @@
+use super::{
+    backups::backup_after_changes, balances::watch_balance, refresh_clients::refresh_clients,
+};
+use crate::{
+    backend::ClientHandle,
+    state::{AppState, FederationRecovery, RecoveryStatus, Restore, SeedBackup},
+};
+use fedimint_core::{config::FederationId, invite_code::InviteCode};
+use futures::{
+    StreamExt,
+    future::{self, Either},
+};
+use std::{
+    collections::BTreeMap,
+    convert::Infallible,
+    pin::pin,
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
+
+pub async fn restore_seed(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    words: String,
+    passphrase: String,
+) {
+    state.lock().unwrap().restore = Restore::Restoring;
+
+    let result = handle.restore_mnemonic(&words, &passphrase).await;
+    let mut state = state.lock().unwrap();
+
+    match result {
+        Ok(()) => {
+            state.restore = Restore::Restored;
+            state.seed_backup = SeedBackup::BackedUp;
+        }
+        Err(e) => state.restore = Restore::Failed(e.to_string()),
+    }
+}
+
+pub async fn recover_federation(
+    handle: &mut ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    invite_code: String,
+) {
+    // The Restore screen only sends invite codes that parse
+    let Ok(invite) = InviteCode::from_str(invite_code.trim()) else {
+        return;
+    };
+    let federation_id = invite.federation_id();
+
+    set_status(state, federation_id, RecoveryStatus::Joining);
+
+    if let Err(e) = handle.recover(&invite_code).await {
+        set_status(state, federation_id, RecoveryStatus::Failed(e.to_string()));
+        return;
+    }
+
+    refresh_clients(handle, state).await;
+    watch_balance(handle, state, federation_id).await;
+    backup_after_changes(handle, state, federation_id).await;
+    watch_recovery(handle, state, federation_id).await;
+}
+
+/// Picks up recoveries interrupted by closing the app, the client resumes them on opening
+pub async fn resume_recovery(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+) {
+    if let Ok(wallet) = handle.get_wallet(federation_id).await
+        && wallet.is_recovering()
+    {
+        watch_recovery(handle, state, federation_id).await;
+    }
+}
+
+async fn watch_recovery(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+) {
+    let Ok(wallet) = handle.get_wallet(federation_id).await else {
+        return;
+    };
+
+    let name = wallet.federation_name().await;
+    let mut updates = wallet.subscribe_recovery();
+
+    {
+        let mut state = state.lock().unwrap();
+        let status = RecoveryStatus::Recovering {
+            complete: 0,
+            total: 0,
+        };
+        set_recovery(&mut state, federation_id, status);
+
+        if let Some(recovery) = state
+            .recoveries
+            .iter_mut()
+            .find(|r| r.federation_id == federation_id)
+        {
+            recovery.name = name;
+        }
+    }
+
+    let task = tokio::spawn({
+        let handle = handle.clone();
+        let state = state.clone();
+
+        async move {
+            // Modules report progress one at a time, so the stream can't tell when all are done
+            let progress = async {
+                let mut modules = BTreeMap::new();
+
+                while let Some((module_id, complete, total)) = updates.next().await {
+                    modules.insert(module_id, (complete, total));
+
+                    let (complete, total) =
+                        modules.values().fold((0, 0), |(c, t), (complete, total)| {
+                            (c + complete, t + total)
+                        });
+                    set_status(
+                        &state,
+                        federation_id,
+                        RecoveryStatus::Recovering { complete, total },
+                    );
+                }
+
+                std::future::pending::<Infallible>().await
+            };
+
+            let status =
+                match future::select(pin!(wallet.wait_for_recovery()), pin!(progress)).await {
+                    Either::Left((Ok(()), _)) => RecoveryStatus::Done,
+                    Either::Left((Err(e), _)) => RecoveryStatus::Failed(e.to_string()),
+                    Either::Right((never, _)) => match never {},
+                };
+            set_status(&state, federation_id, status);
+
+            // Recovered ecash only shows up in the balance once the mint module is back
+            refresh_clients(&handle, &state).await;
+        }
+    });
+    handle.track_task(federation_id, task.abort_handle());
+}
+
+fn set_status(state: &Arc<Mutex<AppState>>, federation_id: FederationId, status: RecoveryStatus) {
+    set_recovery(&mut state.lock().unwrap(), federation_id, status);
+}
+
+fn set_recovery(state: &mut AppState, federation_id: FederationId, status: RecoveryStatus) {
+    match state
+        .recoveries
+        .iter_mut()
+        .find(|r| r.federation_id == federation_id)
+    {
+        Some(recovery) => recovery.status = status,
+        None => state.recoveries.push(FederationRecovery {
+            federation_id,
+            name: None,
+            status,
+        }),
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{
+    backend::ClientHandle,
+    state::{AppState, SeedBackup, SeedReveal},
+};
+use std::sync::{Arc, Mutex};
+
+/// Reads whether the seed was backed up, for the Wallets banner
+pub async fn load_seed_backup(handle: &ClientHandle, state: &Arc<Mutex<AppState>>) {
+    let seed_backup = match handle.seed_backed_up().await {
+        true => SeedBackup::BackedUp,
+        false => SeedBackup::NotBackedUp,
+    };
+
+    state.lock().unwrap().seed_backup = seed_backup;
+}
+
+pub async fn reveal_seed(handle: &ClientHandle, state: &Arc<Mutex<AppState>>) {
+    state.lock().unwrap().seed = SeedReveal::Loading;
+
+    let seed = match handle.show_mnemonic().await {
+        Ok(words) => SeedReveal::Revealed(words),
+        Err(e) => SeedReveal::Failed(e.to_string()),
+    };
+
+    state.lock().unwrap().seed = seed;
+}
+
+pub async fn confirm_seed_backup(handle: &ClientHandle, state: &Arc<Mutex<AppState>>) {
+    let result = handle.confirm_seed_backup().await;
+    let mut state = state.lock().unwrap();
+
+    match result {
+        Ok(()) => {
+            state.seed_backup = SeedBackup::BackedUp;
+            state.seed = SeedReveal::Hidden;
+        }
+        Err(e) => state.seed = SeedReveal::Failed(e.to_string()),
+    }
+}
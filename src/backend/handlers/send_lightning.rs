@@ -0,0 +1,169 @@
+use crate::{
+    backend::{ClientHandle, InvoiceQuote, Wallet},
+    state::{AppState, LightningSend},
+};
+use fedimint_client::OperationId;
+use fedimint_core::{Amount, config::FederationId};
+use fedimint_ln_client::{InternalPayState, LnPayState, PayType};
+use futures::StreamExt;
+use std::sync::{Arc, Mutex};
+
+pub async fn quote_invoice(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+    invoice: String,
+) {
+    state.lock().unwrap().ln_send = LightningSend::Decoding;
+
+    let result = async {
+        let wallet = handle.get_wallet(federation_id).await?;
+        wallet.quote_invoice(&invoice).await
+    }
+    .await;
+
+    state.lock().unwrap().ln_send = match result {
+        Ok(quote) => LightningSend::Confirm(quote),
+        Err(e) => LightningSend::Failed(e.to_string()),
+    };
+}
+
+pub async fn pay_invoice(
+    handle: &ClientHandle,
+    state: &Arc<Mutex<AppState>>,
+    federation_id: FederationId,
+    quote: InvoiceQuote,
+) {
+    let amount = quote.amount;
+    let invoice = quote.invoice.to_string();
+
+    state.lock().unwrap().ln_send = LightningSend::Paying {
+        invoice: invoice.clone(),
+    };
+
+    let result = async {
+        let mut wallet = handle.get_wallet(federation_id).await?;
+        let payment = wallet.pay_invoice(quote).await?;
+
+        anyhow::Ok((wallet, payment))
+    }
+    .await;
+
+    let (wallet, payment) = match result {
+        Ok(result) => result,
+        Err(e) => {
+            state.lock().unwrap().ln_send = LightningSend::Failed(e.to_string());
+            return;
+        }
+    };
+
+    // Gateway payments can take a while to settle, so don't block other messages
+    let task = tokio::spawn({
+        let handle = handle.clone();
+        let state = state.clone();
+
+        async move {
+            let fee = payment.fee;
+            let result = match payment.payment_type {
+                PayType::Lightning(operation_id) => {
+                    track_lightning_pay(&wallet, &state, operation_id, &invoice, amount, fee).await
+                }
+                PayType::Internal(operation_id) => {
+                    track_internal_pay(&wallet, &state, operation_id, &invoice, amount).await
+                }
+            };
+
+            if let Err(e) = result {
+                set_status(&state, &invoice, LightningSend::Failed(e.to_string()));
+            }
+
+            super::refresh_clients::refresh_clients(&handle, &state).await;
+        }
+    });
+    handle.track_task(federation_id, task.abort_handle());
+}
+
+async fn track_lightning_pay(
+    wallet: &Wallet,
+    state: &Arc<Mutex<AppState>>,
+    operation_id: OperationId,
+    invoice: &str,
+    amount: Amount,
+    fee: Amount,
+) -> anyhow::Result<()> {
+    let mut updates = wallet.subscribe_lightning_pay(operation_id).await?;
+
+    while let Some(update) = updates.next().await {
+        let status = match update {
+            LnPayState::Created | LnPayState::AwaitingChange => continue,
+            LnPayState::Funded { .. } => LightningSend::Funded {
+                invoice: invoice.to_string(),
+            },
+            LnPayState::WaitingForRefund { error_reason } => LightningSend::Refunding {
+                invoice: invoice.to_string(),
+                reason: error_reason,
+            },
+            LnPayState::Success { .. } => LightningSend::Succeeded { amount, fee },
+            LnPayState::Refunded { gateway_error } => {
+                LightningSend::Refunded(gateway_error.to_string())
+            }
+            LnPayState::Canceled => LightningSend::Failed("payment was canceled".into()),
+            LnPayState::UnexpectedError { error_message } => LightningSend::Failed(error_message),
+        };
+
+        if !set_status(state, invoice, status) {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Payments to another user of the same federation skip the gateway and its fee
+async fn track_internal_pay(
+    wallet: &Wallet,
+    state: &Arc<Mutex<AppState>>,
+    operation_id: OperationId,
+    invoice: &str,
+    amount: Amount,
+) -> anyhow::Result<()> {
+    let mut updates = wallet.subscribe_internal_pay(operation_id).await?;
+
+    while let Some(update) = updates.next().await {
+        let status = match update {
+            InternalPayState::Funding => continue,
+            InternalPayState::Preimage(_) => LightningSend::Succeeded {
+                amount,
+                fee: Amount::ZERO,
+            },
+            InternalPayState::RefundSuccess { error, .. } => {
+                LightningSend::Refunded(error.to_string())
+            }
+            InternalPayState::RefundError { error_message, .. } => {
+                LightningSend::Failed(error_message)
+            }
+            InternalPayState::FundingFailed { error } => LightningSend::Failed(error.to_string()),
+            InternalPayState::UnexpectedError(error) => LightningSend::Failed(error),
+        };
+
+        if !set_status(state, invoice, status) {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Shows a payment's new status, unless the Send screen has moved on to another invoice since
+///
+/// Returns whether the status was shown
+fn set_status(state: &Arc<Mutex<AppState>>, invoice: &str, status: LightningSend) -> bool {
+    let mut state = state.lock().unwrap();
+
+    if !state.ln_send.is_for_invoice(invoice) {
+        return false;
+    }
+
+    state.ln_send = status;
+    true
+}
@@ -0,0 +1,737 @@
+use fedimint_client::{
+    OperationId,
+    oplog::{ChronologicalOperationLogKey, OperationLogEntry},
+};
+use fedimint_core::{Amount, config::FederationId};
+use fedimint_ln_client::{
+    InternalPayState, LightningOperationMeta, LightningOperationMetaVariant, LnPayState,
+    LnReceiveState,
+};
+use fedimint_mint_client::{
+    MintOperationMeta, MintOperationMetaVariant, ReissueExternalNotesState, SpendOOBState,
+};
+use fedimint_wallet_client::{
+    DepositStateV2, WalletOperationMeta, WalletOperationMetaVariant, WithdrawState,
+};
+use serde::de::DeserializeOwned;
+use std::time::SystemTime;
+
+use super::OperationMeta;
+
+/// What an operation did, from the user's point of view
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OperationKind {
+    EcashSend,
+    EcashReceive,
+    LightningSend,
+    LightningReceive,
+    Deposit,
+    Withdraw,
+    /// Anything else the modules log, e.g. internal bookkeeping
+    Other,
+}
+
+impl OperationKind {
+    /// Whether the operation moves money out of the wallet
+    pub fn is_outgoing(&self) -> bool {
+        matches!(
+            self,
+            OperationKind::EcashSend | OperationKind::LightningSend | OperationKind::Withdraw
+        )
+    }
+}
+
+impl std::fmt::Display for OperationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let kind = match self {
+            OperationKind::EcashSend => "ecash send",
+            OperationKind::EcashReceive => "ecash receive",
+            OperationKind::LightningSend => "lightning send",
+            OperationKind::LightningReceive => "lightning receive",
+            OperationKind::Deposit => "on-chain deposit",
+            OperationKind::Withdraw => "on-chain withdrawal",
+            OperationKind::Other => "other",
+        };
+
+        write!(f, "{kind}")
+    }
+}
+
+/// Where an operation ended up, as far as the operation log knows
+#[derive(Debug, Clone, PartialEq)]
+pub enum OperationStatus {
+    /// Still running, or never followed to the end so no outcome was recorded
+    Pending,
+    Complete,
+    /// The funds came back to us, e.g. a failed payment or reclaimed ecash
+    Refunded,
+    Failed(String),
+}
+
+impl std::fmt::Display for OperationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            OperationStatus::Pending => write!(f, "pending"),
+            OperationStatus::Complete => write!(f, "complete"),
+            OperationStatus::Refunded => write!(f, "refunded"),
+            OperationStatus::Failed(error) => write!(f, "failed: {error}"),
+        }
+    }
+}
+
+/// One entry of a federation client's operation log
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryEntry {
+    pub federation_id: FederationId,
+    pub operation_id: OperationId,
+    pub kind: OperationKind,
+    /// Unknown for deposits until the transaction arrives
+    pub amount: Option<Amount>,
+    pub fee: Option<Amount>,
+    pub time: SystemTime,
+    pub status: OperationStatus,
+    /// Kept in our own database rather than the client's operation log
+    pub label: Option<String>,
+}
+
+impl HistoryEntry {
+    pub fn from_log(
+        federation_id: FederationId,
+        key: ChronologicalOperationLogKey,
+        entry: &OperationLogEntry,
+    ) -> HistoryEntry {
+        let classified = classify(entry);
+
+        HistoryEntry {
+            federation_id,
+            operation_id: key.operation_id,
+            kind: classified.kind,
+            amount: classified.amount,
+            fee: classified.fee,
+            time: key.creation_time,
+            status: classified
+                .outcome
+                .map_or(OperationStatus::Pending, |outcome| outcome.status),
+            label: None,
+        }
+    }
+}
+
+/// A state change reported by a module, described for display
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationUpdate {
+    pub state: String,
+    pub status: OperationStatus,
+    /// A transaction id or similar that only becomes known in this state
+    pub reference: Option<(String, String)>,
+}
+
+impl OperationUpdate {
+    fn new(state: impl Into<String>, status: OperationStatus) -> Self {
+        Self {
+            state: state.into(),
+            status,
+            reference: None,
+        }
+    }
+
+    fn with_reference(mut self, label: &str, value: impl ToString) -> Self {
+        self.reference = Some((label.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// A state an operation was seen in
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transition {
+    pub time: SystemTime,
+    pub state: String,
+}
+
+/// Everything known about a single operation
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationDetail {
+    pub entry: HistoryEntry,
+    /// Invoices, addresses, transaction ids and notes tied to the operation
+    pub references: Vec<(String, String)>,
+    /// The log only keeps the creation and the outcome, anything in between is seen live
+    pub transitions: Vec<Transition>,
+}
+
+impl OperationDetail {
+    pub fn from_log(
+        federation_id: FederationId,
+        key: ChronologicalOperationLogKey,
+        entry: &OperationLogEntry,
+    ) -> OperationDetail {
+        let classified = classify(entry);
+
+        let mut detail = OperationDetail {
+            entry: HistoryEntry {
+                federation_id,
+                operation_id: key.operation_id,
+                kind: classified.kind,
+                amount: classified.amount,
+                fee: classified.fee,
+                time: key.creation_time,
+                // Set from the outcome below, if there is one
+                status: OperationStatus::Pending,
+                label: None,
+            },
+            references: classified.references,
+            transitions: vec![Transition {
+                time: key.creation_time,
+                state: "created".to_string(),
+            }],
+        };
+
+        if let Some(outcome) = classified.outcome {
+            let time = entry.outcome_time().unwrap_or(key.creation_time);
+            detail.apply(outcome, time);
+        }
+
+        detail
+    }
+
+    /// Records a state change, ignoring repeats of the current state
+    pub fn apply(&mut self, update: OperationUpdate, time: SystemTime) {
+        if let Some(reference) = update.reference
+            && !self.references.contains(&reference)
+        {
+            self.references.push(reference);
+        }
+
+        if self.transitions.last().map(|t| &t.state) != Some(&update.state) {
+            self.transitions.push(Transition {
+                time,
+                state: update.state,
+            });
+        }
+
+        self.entry.status = update.status;
+    }
+}
+
+/// The module stream that reports an operation's state changes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum UpdateStream {
+    EcashSpend,
+    EcashReissue,
+    LightningPay,
+    InternalPay,
+    LightningReceive,
+    Deposit,
+    Withdraw,
+}
+
+pub(super) fn update_stream(entry: &OperationLogEntry) -> Option<UpdateStream> {
+    classify(entry).stream
+}
+
+/// Decodes an entry's outcome, `None` if there is none yet or it was written by a module version
+/// we can't read, where `OperationLogEntry::outcome` would panic
+fn outcome<T: DeserializeOwned>(entry: &OperationLogEntry) -> Option<T> {
+    entry.try_outcome().ok().flatten()
+}
+
+/// What the operation log says about an operation, beyond its id and creation time
+struct Classified {
+    kind: OperationKind,
+    amount: Option<Amount>,
+    fee: Option<Amount>,
+    outcome: Option<OperationUpdate>,
+    references: Vec<(String, String)>,
+    stream: Option<UpdateStream>,
+}
+
+fn classify(entry: &OperationLogEntry) -> Classified {
+    let mut classified = Classified {
+        kind: OperationKind::Other,
+        amount: None,
+        fee: None,
+        outcome: None,
+        references: Vec::new(),
+        stream: None,
+    };
+
+    let unreadable = |mut classified: Classified| {
+        classified.references.push((
+            "error".to_string(),
+            "the operation's details could not be read".to_string(),
+        ));
+        classified
+    };
+
+    // The outcome is only recorded once something followed the operation to its end
+    match entry.operation_module_kind() {
+        "mint" => {
+            let Ok(meta) = entry.try_meta::<MintOperationMeta>() else {
+                return unreadable(classified);
+            };
+            classified.amount = Some(meta.amount);
+
+            match meta.variant {
+                MintOperationMetaVariant::SpendOOB { oob_notes, .. } => {
+                    classified.kind = OperationKind::EcashSend;
+                    classified.stream = Some(UpdateStream::EcashSpend);
+                    classified.outcome = outcome::<SpendOOBState>(entry).map(spend_update);
+                    classified
+                        .references
+                        .push(("notes".to_string(), oob_notes.to_string()));
+                }
+                MintOperationMetaVariant::Reissuance { .. } => {
+                    classified.kind = OperationKind::EcashReceive;
+                    classified.stream = Some(UpdateStream::EcashReissue);
+                    classified.outcome =
+                        outcome::<ReissueExternalNotesState>(entry).map(reissue_update);
+                }
+            }
+        }
+        "ln" => {
+            let Ok(meta) = entry.try_meta::<LightningOperationMeta>() else {
+                return unreadable(classified);
+            };
+
+            match meta.variant {
+                LightningOperationMetaVariant::Pay(pay) => {
+                    classified.kind = OperationKind::LightningSend;
+                    classified.amount = pay.invoice.amount_milli_satoshis().map(Amount::from_msats);
+                    classified.fee = Some(pay.fee);
+
+                    if pay.is_internal_payment {
+                        classified.stream = Some(UpdateStream::InternalPay);
+                        classified.outcome =
+                            outcome::<InternalPayState>(entry).map(internal_pay_update);
+                    } else {
+                        classified.stream = Some(UpdateStream::LightningPay);
+                        classified.outcome = outcome::<LnPayState>(entry).map(pay_update);
+                    }
+
+                    classified.references.extend([
+                        ("invoice".to_string(), pay.invoice.to_string()),
+                        (
+                            "payment hash".to_string(),
+                            pay.invoice.payment_hash().to_string(),
+                        ),
+                    ]);
+                }
+                LightningOperationMetaVariant::Receive { invoice, .. } => {
+                    classified.kind = OperationKind::LightningReceive;
+                    classified.amount = invoice.amount_milli_satoshis().map(Amount::from_msats);
+                    classified.stream = Some(UpdateStream::LightningReceive);
+                    classified.outcome = outcome::<LnReceiveState>(entry).map(receive_update);
+                    classified.references.extend([
+                        ("invoice".to_string(), invoice.to_string()),
+                        (
+                            "payment hash".to_string(),
+                            invoice.payment_hash().to_string(),
+                        ),
+                    ]);
+                }
+                _ => {}
+            }
+
+            if let Ok(OperationMeta { lnurl: Some(lnurl) }) =
+                serde_json::from_value(meta.extra_meta)
+            {
+                classified.references.push(("lnurl".to_string(), lnurl));
+            }
+        }
+        "wallet" => {
+            let Ok(meta) = entry.try_meta::<WalletOperationMeta>() else {
+                return unreadable(classified);
+            };
+
+            match meta.variant {
+                WalletOperationMetaVariant::Deposit { address, .. } => {
+                    classified.kind = OperationKind::Deposit;
+                    classified.stream = Some(UpdateStream::Deposit);
+                    classified
+                        .references
+                        .push(("address".to_string(), address.assume_checked().to_string()));
+
+                    if let Some(outcome) = outcome::<DepositStateV2>(entry) {
+                        if let DepositStateV2::Claimed { btc_deposited, .. } = &outcome {
+                            classified.amount = Some(Amount::from_sats(btc_deposited.to_sat()));
+                        }
+
+                        classified.outcome = Some(deposit_update(outcome));
+                    }
+                }
+                WalletOperationMetaVariant::Withdraw {
+                    address,
+                    amount,
+                    fee,
+                    ..
+                } => {
+                    classified.kind = OperationKind::Withdraw;
+                    classified.amount = Some(Amount::from_sats(amount.to_sat()));
+                    classified.fee = Some(Amount::from_sats(fee.amount().to_sat()));
+                    classified.stream = Some(UpdateStream::Withdraw);
+                    classified.outcome = outcome::<WithdrawState>(entry).map(withdraw_update);
+                    classified
+                        .references
+                        .push(("address".to_string(), address.assume_checked().to_string()));
+                }
+                _ => {}
+            }
+        }
+        _ => {}
+    }
+
+    classified
+}
+
+pub(super) fn spend_update(state: SpendOOBState) -> OperationUpdate {
+    match state {
+        SpendOOBState::Created => {
+            OperationUpdate::new("waiting for the recipient", OperationStatus::Pending)
+        }
+        SpendOOBState::UserCanceledProcessing => {
+            OperationUpdate::new("reclaiming the notes", OperationStatus::Pending)
+        }
+        SpendOOBState::UserCanceledSuccess => {
+            OperationUpdate::new("notes reclaimed", OperationStatus::Refunded)
+        }
+        SpendOOBState::UserCanceledFailure => OperationUpdate::new(
+            "recipient redeemed the notes before they were reclaimed",
+            OperationStatus::Complete,
+        ),
+        SpendOOBState::Success => {
+            OperationUpdate::new("recipient redeemed the notes", OperationStatus::Complete)
+        }
+        SpendOOBState::Refunded => {
+            OperationUpdate::new("timed out, notes reclaimed", OperationStatus::Refunded)
+        }
+    }
+}
+
+pub(super) fn reissue_update(state: ReissueExternalNotesState) -> OperationUpdate {
+    match state {
+        ReissueExternalNotesState::Created => {
+            OperationUpdate::new("submitted to the federation", OperationStatus::Pending)
+        }
+        ReissueExternalNotesState::Issuing => {
+            OperationUpdate::new("federation is issuing new notes", OperationStatus::Pending)
+        }
+        ReissueExternalNotesState::Done => {
+            OperationUpdate::new("notes redeemed", OperationStatus::Complete)
+        }
+        ReissueExternalNotesState::Failed(error) => {
+            OperationUpdate::new(format!("failed: {error}"), OperationStatus::Failed(error))
+        }
+    }
+}
+
+pub(super) fn pay_update(state: LnPayState) -> OperationUpdate {
+    match state {
+        LnPayState::Created => OperationUpdate::new("created", OperationStatus::Pending),
+        LnPayState::Funded { block_height } => OperationUpdate::new(
+            format!("funded at block {block_height}, gateway is routing"),
+            OperationStatus::Pending,
+        ),
+        LnPayState::AwaitingChange => {
+            OperationUpdate::new("paid, waiting for change", OperationStatus::Pending)
+        }
+        LnPayState::WaitingForRefund { error_reason } => OperationUpdate::new(
+            format!("waiting for refund: {error_reason}"),
+            OperationStatus::Pending,
+        ),
+        LnPayState::Success { preimage } => OperationUpdate::new("paid", OperationStatus::Complete)
+            .with_reference("preimage", preimage),
+        LnPayState::Refunded { gateway_error } => OperationUpdate::new(
+            format!("refunded: {gateway_error}"),
+            OperationStatus::Refunded,
+        ),
+        LnPayState::Canceled => OperationUpdate::new(
+            "canceled",
+            OperationStatus::Failed("payment was canceled".to_string()),
+        ),
+        LnPayState::UnexpectedError { error_message } => OperationUpdate::new(
+            format!("failed: {error_message}"),
+            OperationStatus::Failed(error_message),
+        ),
+    }
+}
+
+pub(super) fn internal_pay_update(state: InternalPayState) -> OperationUpdate {
+    match state {
+        InternalPayState::Funding => {
+            OperationUpdate::new("funding the payment", OperationStatus::Pending)
+        }
+        InternalPayState::Preimage(_) => OperationUpdate::new("paid", OperationStatus::Complete),
+        InternalPayState::RefundSuccess { error, .. } => {
+            OperationUpdate::new(format!("refunded: {error}"), OperationStatus::Refunded)
+        }
+        InternalPayState::RefundError { error_message, .. }
+        | InternalPayState::UnexpectedError(error_message) => OperationUpdate::new(
+            format!("failed: {error_message}"),
+            OperationStatus::Failed(error_message),
+        ),
+        InternalPayState::FundingFailed { error } => OperationUpdate::new(
+            format!("funding failed: {error}"),
+            OperationStatus::Failed(error.to_string()),
+        ),
+    }
+}
+
+pub(super) fn receive_update(state: LnReceiveState) -> OperationUpdate {
+    match state {
+        LnReceiveState::Created => OperationUpdate::new("created", OperationStatus::Pending),
+        LnReceiveState::WaitingForPayment { .. } => {
+            OperationUpdate::new("waiting for payment", OperationStatus::Pending)
+        }
+        LnReceiveState::Funded | LnReceiveState::AwaitingFunds => {
+            OperationUpdate::new("payment received, claiming", OperationStatus::Pending)
+        }
+        LnReceiveState::Claimed => OperationUpdate::new("claimed", OperationStatus::Complete),
+        LnReceiveState::Canceled { reason } => OperationUpdate::new(
+            format!("canceled: {reason}"),
+            OperationStatus::Failed(reason.to_string()),
+        ),
+    }
+}
+
+pub(super) fn deposit_update(state: DepositStateV2) -> OperationUpdate {
+    match state {
+        DepositStateV2::WaitingForTransaction => {
+            OperationUpdate::new("waiting for the transaction", OperationStatus::Pending)
+        }
+        DepositStateV2::WaitingForConfirmation {
+            btc_deposited,
+            btc_out_point,
+        } => OperationUpdate::new(
+            format!(
+                "{} sats seen, waiting for confirmations",
+                btc_deposited.to_sat()
+            ),
+            OperationStatus::Pending,
+        )
+        .with_reference("txid", btc_out_point.txid),
+        DepositStateV2::Confirmed { btc_out_point, .. } => {
+            OperationUpdate::new("confirmed, issuing ecash", OperationStatus::Pending)
+                .with_reference("txid", btc_out_point.txid)
+        }
+        DepositStateV2::Claimed { btc_out_point, .. } => {
+            OperationUpdate::new("claimed", OperationStatus::Complete)
+                .with_reference("txid", btc_out_point.txid)
+        }
+        DepositStateV2::Failed(error) => {
+            OperationUpdate::new(format!("failed: {error}"), OperationStatus::Failed(error))
+        }
+    }
+}
+
+pub(super) fn withdraw_update(state: WithdrawState) -> OperationUpdate {
+    match state {
+        WithdrawState::Created => {
+            OperationUpdate::new("submitted to the federation", OperationStatus::Pending)
+        }
+        WithdrawState::Succeeded(txid) => {
+            OperationUpdate::new("broadcast", OperationStatus::Complete)
+                .with_reference("txid", txid)
+        }
+        WithdrawState::Failed(error) => {
+            OperationUpdate::new(format!("failed: {error}"), OperationStatus::Failed(error))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fedimint_client::oplog::{JsonStringed, OperationOutcome};
+    use fedimint_core::bitcoin::{self, OutPoint};
+    use serde_json::json;
+
+    fn entry(
+        kind: &str,
+        meta: serde_json::Value,
+        outcome: Option<serde_json::Value>,
+    ) -> OperationLogEntry {
+        OperationLogEntry::new(
+            kind.to_string(),
+            JsonStringed(meta),
+            outcome.map(|outcome| OperationOutcome {
+                time: SystemTime::now(),
+                outcome: JsonStringed(outcome),
+            }),
+        )
+    }
+
+    fn key() -> ChronologicalOperationLogKey {
+        ChronologicalOperationLogKey {
+            creation_time: SystemTime::UNIX_EPOCH,
+            operation_id: OperationId([1; 32]),
+        }
+    }
+
+    #[test]
+    fn undecodable_meta_is_marked_instead_of_panicking() {
+        for kind in ["mint", "ln", "wallet"] {
+            let entry = entry(kind, json!({ "unexpected": true }), Some(json!("Success")));
+            let classified = classify(&entry);
+
+            assert_eq!(classified.kind, OperationKind::Other);
+            assert_eq!(classified.outcome, None);
+            assert_eq!(classified.stream, None);
+            assert_eq!(classified.references.len(), 1);
+            assert_eq!(classified.references[0].0, "error");
+        }
+    }
+
+    #[test]
+    fn undecodable_outcome_counts_as_missing() {
+        let garbage = entry("mint", json!({}), Some(json!({ "unexpected": [1, 2] })));
+        assert!(outcome::<SpendOOBState>(&garbage).is_none());
+
+        let settled = entry("mint", json!({}), Some(json!("Success")));
+        assert!(matches!(
+            outcome::<SpendOOBState>(&settled),
+            Some(SpendOOBState::Success)
+        ));
+
+        let running = entry("mint", json!({}), None);
+        assert!(outcome::<SpendOOBState>(&running).is_none());
+    }
+
+    #[test]
+    fn other_modules_are_listed_as_pending_other() {
+        let entry = entry("stability_pool", json!({ "anything": 1 }), None);
+        let listed = HistoryEntry::from_log(FederationId::dummy(), key(), &entry);
+
+        assert_eq!(listed.kind, OperationKind::Other);
+        assert_eq!(listed.status, OperationStatus::Pending);
+        assert_eq!(listed.amount, None);
+        assert!(classify(&entry).references.is_empty());
+    }
+
+    #[test]
+    fn spends_map_to_statuses() {
+        assert_eq!(
+            spend_update(SpendOOBState::Created).status,
+            OperationStatus::Pending
+        );
+        assert_eq!(
+            spend_update(SpendOOBState::UserCanceledProcessing).status,
+            OperationStatus::Pending
+        );
+        assert_eq!(
+            spend_update(SpendOOBState::UserCanceledSuccess).status,
+            OperationStatus::Refunded
+        );
+        assert_eq!(
+            spend_update(SpendOOBState::UserCanceledFailure).status,
+            OperationStatus::Complete
+        );
+        assert_eq!(
+            spend_update(SpendOOBState::Success).status,
+            OperationStatus::Complete
+        );
+        assert_eq!(
+            spend_update(SpendOOBState::Refunded).status,
+            OperationStatus::Refunded
+        );
+    }
+
+    #[test]
+    fn reissues_map_to_statuses() {
+        assert_eq!(
+            reissue_update(ReissueExternalNotesState::Issuing).status,
+            OperationStatus::Pending
+        );
+        assert_eq!(
+            reissue_update(ReissueExternalNotesState::Done).status,
+            OperationStatus::Complete
+        );
+        assert_eq!(
+            reissue_update(ReissueExternalNotesState::Failed("spent".to_string())).status,
+            OperationStatus::Failed("spent".to_string())
+        );
+    }
+
+    #[test]
+    fn payments_map_to_statuses() {
+        assert_eq!(
+            pay_update(LnPayState::Funded { block_height: 7 }).status,
+            OperationStatus::Pending
+        );
+        assert_eq!(
+            pay_update(LnPayState::WaitingForRefund {
+                error_reason: "no route".to_string()
+            })
+            .status,
+            OperationStatus::Pending
+        );
+        assert_eq!(
+            pay_update(LnPayState::Canceled).status,
+            OperationStatus::Failed("payment was canceled".to_string())
+        );
+        assert_eq!(
+            pay_update(LnPayState::UnexpectedError {
+                error_message: "boom".to_string()
+            })
+            .status,
+            OperationStatus::Failed("boom".to_string())
+        );
+    }
+
+    #[test]
+    fn receives_map_to_statuses() {
+        assert_eq!(
+            receive_update(LnReceiveState::Created).status,
+            OperationStatus::Pending
+        );
+        assert_eq!(
+            receive_update(LnReceiveState::Funded).status,
+            OperationStatus::Pending
+        );
+        assert_eq!(
+            receive_update(LnReceiveState::Claimed).status,
+            OperationStatus::Complete
+        );
+    }
+
+    #[test]
+    fn deposits_carry_their_txid() {
+        let seen = deposit_update(DepositStateV2::WaitingForConfirmation {
+            btc_deposited: bitcoin::Amount::from_sat(50_000),
+            btc_out_point: OutPoint::null(),
+        });
+
+        assert_eq!(seen.status, OperationStatus::Pending);
+        assert_eq!(seen.state, "50000 sats seen, waiting for confirmations");
+        assert_eq!(
+            seen.reference,
+            Some(("txid".to_string(), OutPoint::null().txid.to_string()))
+        );
+
+        let claimed = deposit_update(DepositStateV2::Claimed {
+            btc_deposited: bitcoin::Amount::from_sat(50_000),
+            btc_out_point: OutPoint::null(),
+        });
+        assert_eq!(claimed.status, OperationStatus::Complete);
+
+        assert_eq!(
+            deposit_update(DepositStateV2::Failed("reorg".to_string())).status,
+            OperationStatus::Failed("reorg".to_string())
+        );
+    }
+
+    #[test]
+    fn withdrawals_map_to_statuses() {
+        assert_eq!(
+            withdraw_update(WithdrawState::Created).status,
+            OperationStatus::Pending
+        );
+
+        let broadcast = withdraw_update(WithdrawState::Succeeded(OutPoint::null().txid));
+        assert_eq!(broadcast.status, OperationStatus::Complete);
+        assert_eq!(broadcast.reference.unwrap().0, "txid");
+
+        assert_eq!(
+            withdraw_update(WithdrawState::Failed("fees".to_string())).status,
+            OperationStatus::Failed("fees".to_string())
+        );
+    }
+}
@@ -0,0 +1,547 @@
+use anyhow::{Context, Result, anyhow};
+use fedimint_core::Amount;
+use fedimint_core::bitcoin::hashes::{Hash, sha256};
+use lightning_invoice::{Bolt11Invoice, Bolt11InvoiceDescriptionRef};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use std::{future::Future, str::FromStr, time::Duration};
+use url::Url;
+
+/// How long an LNURL service gets to answer, an unresponsive one shouldn't hang the request
+const LNURL_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// The HTTP access LNURL needs, swappable so resolution can run against a stand-in server
+pub trait HttpClient: Send + Sync {
+    /// Fetches `url` and returns the response body
+    fn get(&self, url: &str) -> impl Future<Output = Result<String>> + Send;
+}
+
+#[derive(Debug, Clone)]
+pub struct ReqwestClient(reqwest::Client);
+
+impl Default for ReqwestClient {
+    fn default() -> Self {
+        // Same as `reqwest::Client::new`, which panics if the TLS backend can't be set up
+        let client = reqwest::Client::builder()
+            .timeout(LNURL_TIMEOUT)
+            .build()
+            .expect("TLS backend cannot be initialized");
+
+        Self(client)
+    }
+}
+
+impl HttpClient for ReqwestClient {
+    async fn get(&self, url: &str) -> Result<String> {
+        let response = self.0.get(url).send().await?;
+
+        Ok(response.text().await?)
+    }
+}
+
+/// An LNURL-pay request, from either an `lnurl1...` string or a lightning address
+#[derive(Debug, Clone, PartialEq)]
+pub struct PayRequest {
+    /// The string the user entered
+    pub target: String,
+    pub callback: String,
+    pub min_sendable: Amount,
+    pub max_sendable: Amount,
+    pub description: String,
+    /// The raw metadata, invoices from the service must commit to its hash
+    pub metadata: String,
+    /// Maximum comment length, zero if comments aren't accepted
+    pub comment_allowed: u16,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PayResponse {
+    tag: String,
+    callback: String,
+    min_sendable: u64,
+    max_sendable: u64,
+    metadata: String,
+    #[serde(default)]
+    comment_allowed: u16,
+}
+
+/// An LNURL-withdraw request, e.g. from a faucet or voucher
+#[derive(Debug, Clone, PartialEq)]
+pub struct LnurlWithdrawRequest {
+    /// The string the user entered
+    pub target: String,
+    pub callback: String,
+    pub k1: String,
+    pub min_withdrawable: Amount,
+    pub max_withdrawable: Amount,
+    pub description: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WithdrawResponse {
+    tag: String,
+    callback: String,
+    k1: String,
+    #[serde(default)]
+    min_withdrawable: u64,
+    max_withdrawable: u64,
+    #[serde(default)]
+    default_description: String,
+}
+
+#[derive(Deserialize)]
+struct InvoiceResponse {
+    pr: String,
+}
+
+/// Whether `target` looks like an LNURL or a lightning address rather than an invoice
+pub fn is_lnurl(target: &str) -> bool {
+    let target = strip_scheme(target.trim());
+
+    target.to_lowercase().starts_with("lnurl1") || is_lightning_address(target)
+}
+
+fn is_lightning_address(target: &str) -> bool {
+    target
+        .split_once('@')
+        .is_some_and(|(user, domain)| !user.is_empty() && domain.contains('.'))
+}
+
+fn strip_scheme(target: &str) -> &str {
+    match target.get(..10) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("lightning:") => &target[10..],
+        _ => target,
+    }
+}
+
+/// Turns an `lnurl1...` string or a `user@domain` lightning address into the URL it points at
+pub fn decode(target: &str) -> Result<Url> {
+    let target = strip_scheme(target.trim());
+
+    if let Some((user, domain)) = target.split_once('@')
+        && is_lightning_address(target)
+    {
+        return Ok(Url::parse(&format!(
+            "https://{domain}/.well-known/lnurlp/{}",
+            user.to_lowercase()
+        ))?);
+    }
+
+    let (hrp, data) = bech32::decode(target).map_err(|e| anyhow!("invalid LNURL: {e}"))?;
+
+    if !hrp.as_str().eq_ignore_ascii_case("lnurl") {
+        return Err(anyhow!("invalid LNURL: unexpected prefix {hrp}"));
+    }
+
+    Ok(Url::parse(&String::from_utf8(data)?)?)
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LnurlResolver<C: HttpClient = ReqwestClient> {
+    http: C,
+}
+
+impl LnurlResolver {
+    pub fn new() -> Self {
+        Self::with_client(ReqwestClient::default())
+    }
+}
+
+impl<C: HttpClient> LnurlResolver<C> {
+    pub fn with_client(http: C) -> Self {
+        Self { http }
+    }
+
+    /// Fetches a JSON response, turning LNURL `{"status": "ERROR"}` replies into errors
+    async fn get_json<T: DeserializeOwned>(&self, url: &Url) -> Result<T> {
+        let body = self.http.get(url.as_str()).await?;
+        let value: serde_json::Value =
+            serde_json::from_str(&body).context("invalid LNURL response")?;
+
+        if value["status"].as_str() == Some("ERROR") {
+            let reason = value["reason"].as_str().unwrap_or("unknown error");
+            return Err(anyhow!("LNURL service returned an error: {reason}"));
+        }
+
+        serde_json::from_value(value).context("invalid LNURL response")
+    }
+
+    pub async fn fetch_pay_request(&self, target: &str) -> Result<PayRequest> {
+        let response: PayResponse = self.get_json(&decode(target)?).await?;
+
+        if response.tag != "payRequest" {
+            return Err(anyhow!("not an LNURL-pay request: {}", response.tag));
+        }
+
+        if response.min_sendable > response.max_sendable {
+            return Err(anyhow!("LNURL service offers an empty amount range"));
+        }
+
+        // Metadata is a JSON encoded list of [mime type, content] pairs
+        let metadata: Vec<(String, serde_json::Value)> =
+            serde_json::from_str(&response.metadata).context("invalid LNURL metadata")?;
+        let description = metadata
+            .iter()
+            .find(|(mime, _)| mime == "text/plain")
+            .and_then(|(_, content)| content.as_str())
+            .unwrap_or_default()
+            .to_string();
+
+        Ok(PayRequest {
+            target: target.trim().to_string(),
+            callback: response.callback,
+            min_sendable: Amount::from_msats(response.min_sendable),
+            max_sendable: Amount::from_msats(response.max_sendable),
+            description,
+            metadata: response.metadata,
+            comment_allowed: response.comment_allowed,
+        })
+    }
+
+    /// Asks the LNURL service for an invoice over `amount`
+    pub async fn request_invoice(
+        &self,
+        request: &PayRequest,
+        amount: Amount,
+        comment: &str,
+    ) -> Result<Bolt11Invoice> {
+        if amount < request.min_sendable || amount > request.max_sendable {
+            return Err(anyhow!(
+                "amount must be between {} and {} msat",
+                request.min_sendable.msats,
+                request.max_sendable.msats
+            ));
+        }
+
+        if comment.chars().count() > usize::from(request.comment_allowed) {
+            return Err(anyhow!(
+                "comment is limited to {} characters",
+                request.comment_allowed
+            ));
+        }
+
+        let mut callback = Url::parse(&request.callback).context("invalid LNURL callback")?;
+        callback
+            .query_pairs_mut()
+            .append_pair("amount", &amount.msats.to_string());
+
+        if !comment.is_empty() {
+            callback.query_pairs_mut().append_pair("comment", comment);
+        }
+
+        let response: InvoiceResponse = self.get_json(&callback).await?;
+        let invoice = Bolt11Invoice::from_str(&response.pr)
+            .map_err(|e| anyhow!("LNURL service returned an invalid invoice: {e}"))?;
+
+        // Don't trust the service to bill what we asked for
+        if invoice.amount_milli_satoshis() != Some(amount.msats) {
+            return Err(anyhow!(
+                "LNURL service returned an invoice for the wrong amount"
+            ));
+        }
+
+        // LUD-06: the invoice has to commit to the metadata the user was shown
+        let expected = sha256::Hash::hash(request.metadata.as_bytes());
+        match invoice.description() {
+            Bolt11InvoiceDescriptionRef::Hash(hash) if hash.0[..] == expected[..] => {}
+            _ => {
+                return Err(anyhow!(
+                    "LNURL service returned an invoice for a different description"
+                ));
+            }
+        }
+
+        Ok(invoice)
+    }
+
+    pub async fn fetch_withdraw_request(&self, target: &str) -> Result<LnurlWithdrawRequest> {
+        let response: WithdrawResponse = self.get_json(&decode(target)?).await?;
+
+        if response.tag != "withdrawRequest" {
+            return Err(anyhow!("not an LNURL-withdraw request: {}", response.tag));
+        }
+
+        if response.max_withdrawable == 0 || response.min_withdrawable > response.max_withdrawable {
+            return Err(anyhow!("LNURL service offers nothing to withdraw"));
+        }
+
+        Ok(LnurlWithdrawRequest {
+            target: target.trim().to_string(),
+            callback: response.callback,
+            k1: response.k1,
+            min_withdrawable: Amount::from_msats(response.min_withdrawable),
+            max_withdrawable: Amount::from_msats(response.max_withdrawable),
+            description: response.default_description,
+        })
+    }
+
+    /// Hands our invoice to the LNURL-withdraw service, which then pays it
+    pub async fn submit_withdraw(
+        &self,
+        request: &LnurlWithdrawRequest,
+        invoice: &Bolt11Invoice,
+    ) -> Result<()> {
+        let mut callback = Url::parse(&request.callback).context("invalid LNURL callback")?;
+        callback
+            .query_pairs_mut()
+            .append_pair("k1", &request.k1)
+            .append_pair("pr", &invoice.to_string());
+
+        // Successful replies are just {"status": "OK"}, errors are handled by get_json
+        let _: serde_json::Value = self.get_json(&callback).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bech32::{Bech32, Hrp};
+    use fedimint_core::bitcoin::secp256k1::{Secp256k1, SecretKey};
+    use lightning_invoice::{Currency, InvoiceBuilder, PaymentSecret};
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    const METADATA: &str = r#"[["text/plain","Coffee"]]"#;
+
+    /// Stands in for an LNURL service, answering with canned bodies keyed by URL sans query
+    #[derive(Default)]
+    struct StubServer {
+        responses: BTreeMap<String, String>,
+        requested: Mutex<Vec<String>>,
+    }
+
+    impl StubServer {
+        fn with(mut self, url: &str, body: serde_json::Value) -> Self {
+            self.responses.insert(url.to_string(), body.to_string());
+            self
+        }
+
+        fn requested(&self) -> Vec<String> {
+            self.requested.lock().unwrap().clone()
+        }
+    }
+
+    impl HttpClient for StubServer {
+        async fn get(&self, url: &str) -> Result<String> {
+            self.requested.lock().unwrap().push(url.to_string());
+
+            let base = url.split('?').next().unwrap_or(url);
+            self.responses
+                .get(base)
+                .cloned()
+                .ok_or_else(|| anyhow!("no response for {url}"))
+        }
+    }
+
+    fn encode_lnurl(url: &str) -> String {
+        bech32::encode::<Bech32>(Hrp::parse("lnurl").unwrap(), url.as_bytes()).unwrap()
+    }
+
+    fn pay_response(min_sendable: u64, max_sendable: u64) -> serde_json::Value {
+        serde_json::json!({
+            "tag": "payRequest",
+            "callback": "https://pay.example.com/callback",
+            "minSendable": min_sendable,
+            "maxSendable": max_sendable,
+            "metadata": METADATA,
+            "commentAllowed": 10,
+        })
+    }
+
+    fn pay_request() -> PayRequest {
+        PayRequest {
+            target: "alice@pay.example.com".to_string(),
+            callback: "https://pay.example.com/callback".to_string(),
+            min_sendable: Amount::from_msats(1_000),
+            max_sendable: Amount::from_msats(1_000_000),
+            description: "Coffee".to_string(),
+            metadata: METADATA.to_string(),
+            comment_allowed: 10,
+        }
+    }
+
+    /// A signed regtest invoice committing to `metadata`
+    fn invoice(amount_msat: u64, metadata: &str) -> String {
+        let secp = Secp256k1::new();
+        let key = SecretKey::from_slice(&[42; 32]).unwrap();
+
+        InvoiceBuilder::new(Currency::Regtest)
+            .description_hash(sha256::Hash::hash(metadata.as_bytes()))
+            .payment_hash(sha256::Hash::hash(&[1; 32]))
+            .payment_secret(PaymentSecret([2; 32]))
+            .current_timestamp()
+            .min_final_cltv_expiry_delta(144)
+            .amount_milli_satoshis(amount_msat)
+            .build_signed(|hash| secp.sign_ecdsa_recoverable(hash, &key))
+            .unwrap()
+            .to_string()
+    }
+
+    fn resolver(server: StubServer) -> LnurlResolver<StubServer> {
+        LnurlResolver::with_client(server)
+    }
+
+    #[test]
+    fn decodes_bech32_lnurls() {
+        let url = "https://service.example.com/lnurl-pay?user=alice";
+        let lnurl = encode_lnurl(url);
+
+        assert!(is_lnurl(&lnurl));
+        assert_eq!(decode(&lnurl).unwrap().as_str(), url);
+        assert_eq!(decode(&lnurl.to_uppercase()).unwrap().as_str(), url);
+        assert_eq!(decode(&format!("LIGHTNING:{lnurl}")).unwrap().as_str(), url);
+    }
+
+    #[test]
+    fn rejects_other_bech32_strings() {
+        let other =
+            bech32::encode::<Bech32>(Hrp::parse("lnbc").unwrap(), b"https://x.com").unwrap();
+
+        assert!(!is_lnurl(&other));
+        assert!(decode(&other).is_err());
+        assert!(decode("lnurl1notbech32").is_err());
+    }
+
+    #[test]
+    fn decodes_lightning_addresses() {
+        assert!(is_lnurl("alice@example.com"));
+        assert!(is_lnurl("lightning:alice@example.com"));
+        assert_eq!(
+            decode("Alice@example.com").unwrap().as_str(),
+            "https://example.com/.well-known/lnurlp/alice"
+        );
+
+        assert!(!is_lnurl("@example.com"));
+        assert!(!is_lnurl("alice@localhost"));
+        assert!(!is_lnurl("lnbcrt10u1p"));
+    }
+
+    #[tokio::test]
+    async fn fetches_pay_requests() {
+        let server = StubServer::default().with(
+            "https://example.com/.well-known/lnurlp/alice",
+            pay_response(1_000, 1_000_000),
+        );
+
+        let request = resolver(server)
+            .fetch_pay_request("alice@example.com")
+            .await
+            .unwrap();
+
+        assert_eq!(request.min_sendable, Amount::from_msats(1_000));
+        assert_eq!(request.max_sendable, Amount::from_msats(1_000_000));
+        assert_eq!(request.description, "Coffee");
+        assert_eq!(request.metadata, METADATA);
+        assert_eq!(request.comment_allowed, 10);
+    }
+
+    #[tokio::test]
+    async fn rejects_empty_amount_ranges() {
+        let server = StubServer::default().with(
+            "https://example.com/.well-known/lnurlp/alice",
+            pay_response(2_000, 1_000),
+        );
+
+        let error = resolver(server)
+            .fetch_pay_request("alice@example.com")
+            .await
+            .unwrap_err();
+
+        assert!(error.to_string().contains("empty amount range"));
+    }
+
+    #[tokio::test]
+    async fn surfaces_service_errors() {
+        let server = StubServer::default().with(
+            "https://example.com/.well-known/lnurlp/alice",
+            serde_json::json!({ "status": "ERROR", "reason": "no such user" }),
+        );
+
+        let error = resolver(server)
+            .fetch_pay_request("alice@example.com")
+            .await
+            .unwrap_err();
+
+        assert!(error.to_string().contains("no such user"));
+    }
+
+    #[tokio::test]
+    async fn requests_invoices() {
+        let server = StubServer::default().with(
+            "https://pay.example.com/callback",
+            serde_json::json!({ "pr": invoice(21_000, METADATA) }),
+        );
+        let resolver = resolver(server);
+
+        let invoice = resolver
+            .request_invoice(&pay_request(), Amount::from_msats(21_000), "thanks")
+            .await
+            .unwrap();
+
+        assert_eq!(invoice.amount_milli_satoshis(), Some(21_000));
+        assert_eq!(
+            resolver.http.requested(),
+            vec!["https://pay.example.com/callback?amount=21000&comment=thanks"]
+        );
+    }
+
+    #[tokio::test]
+    async fn checks_amount_and_comment_before_asking() {
+        let resolver = resolver(StubServer::default());
+        let request = pay_request();
+
+        assert!(
+            resolver
+                .request_invoice(&request, Amount::from_msats(999), "")
+                .await
+                .is_err()
+        );
+        assert!(
+            resolver
+                .request_invoice(&request, Amount::from_msats(1_000_001), "")
+                .await
+                .is_err()
+        );
+        assert!(
+            resolver
+                .request_invoice(&request, Amount::from_msats(1_000), "way too long")
+                .await
+                .is_err()
+        );
+        assert!(resolver.http.requested().is_empty());
+    }
+
+    #[tokio::test]
+    async fn rejects_invoices_for_the_wrong_amount() {
+        let server = StubServer::default().with(
+            "https://pay.example.com/callback",
+            serde_json::json!({ "pr": invoice(50_000, METADATA) }),
+        );
+
+        let error = resolver(server)
+            .request_invoice(&pay_request(), Amount::from_msats(21_000), "")
+            .await
+            .unwrap_err();
+
+        assert!(error.to_string().contains("wrong amount"));
+    }
+
+    #[tokio::test]
+    async fn rejects_invoices_for_other_metadata() {
+        let server = StubServer::default().with(
+            "https://pay.example.com/callback",
+            serde_json::json!({ "pr": invoice(21_000, r#"[["text/plain","Tea"]]"#) }),
+        );
+
+        let error = resolver(server)
+            .request_invoice(&pay_request(), Amount::from_msats(21_000), "")
+            .await
+            .unwrap_err();
+
+        assert!(error.to_string().contains("different description"));
+    }
+}
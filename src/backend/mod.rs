@@ -1,9 +1,28 @@
+mod client_handle;
 mod database;
+mod dates;
+mod export;
 mod handlers;
+mod history;
+mod lnurl;
+mod passphrase;
+mod profiles;
 mod wallet;
-mod client_handle;
 
-pub use database::*;
 pub use client_handle::*;
+pub use database::*;
+pub use dates::{civil_date, parse_date, rfc3339};
+pub use export::{ExportFilter, ExportFormat, default_export_path, export, export_command};
 pub use handlers::handle_messages;
-pub use wallet::Wallet;
+pub use history::{
+    HistoryEntry, OperationDetail, OperationKind, OperationStatus, OperationUpdate, Transition,
+};
+pub use lnurl::{LnurlResolver, LnurlWithdrawRequest, PayRequest, is_lnurl};
+pub use profiles::{
+    DEFAULT_PROFILE, create_profile, delete_profile, list_profiles, migrate_single_wallet,
+    rename_profile,
+};
+pub use wallet::{
+    ECASH_SPEND_TIMEOUT, EcashPreview, FederationInfo, GatewayInfo, GuardianHealth, GuardianInfo,
+    InvoiceQuote, ModuleInfo, OperationMeta, OutgoingSpend, Wallet, WithdrawAmount, WithdrawQuote,
+};
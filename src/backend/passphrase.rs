@@ -0,0 +1,114 @@
+use super::EncryptedSeed;
+use anyhow::{Result, anyhow};
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::{ChaCha20Poly1305, Key, KeyInit, Nonce, aead::Aead};
+use rand::{RngCore, thread_rng};
+
+/// Argon2id memory cost in KiB, high enough to make guessing passphrases expensive
+const KDF_M_COST: u32 = 64 * 1024;
+const KDF_T_COST: u32 = 3;
+const KDF_P_COST: u32 = 1;
+
+/// Caps on the parameters a stored record can ask for, so a tampered one can't exhaust memory or
+/// stall unlocking. Well above anything `encrypt` writes
+const MAX_M_COST: u32 = 256 * 1024;
+const MAX_T_COST: u32 = 10;
+const MAX_P_COST: u32 = 8;
+
+/// Seed entropy held in memory once the passphrase was entered
+#[derive(Clone)]
+pub struct UnlockedSeed(pub Vec<u8>);
+
+// Keep the seed out of debug output
+impl std::fmt::Debug for UnlockedSeed {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "UnlockedSeed(..)")
+    }
+}
+
+fn derive_key(passphrase: &str, salt: &[u8], m_cost: u32, t_cost: u32, p_cost: u32) -> Result<Key> {
+    let params = Params::new(m_cost, t_cost, p_cost, Some(32))
+        .map_err(|e| anyhow!("invalid KDF parameters: {e}"))?;
+    let mut key = Key::default();
+
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| anyhow!("failed to derive key: {e}"))?;
+
+    Ok(key)
+}
+
+/// Encrypts the seed entropy with a key derived from `passphrase`
+pub fn encrypt(entropy: &[u8], passphrase: &str) -> Result<EncryptedSeed> {
+    let mut salt = vec![0; 16];
+    let mut nonce = vec![0; 12];
+    thread_rng().fill_bytes(&mut salt);
+    thread_rng().fill_bytes(&mut nonce);
+
+    let key = derive_key(passphrase, &salt, KDF_M_COST, KDF_T_COST, KDF_P_COST)?;
+    let ciphertext = ChaCha20Poly1305::new(&key)
+        .encrypt(Nonce::from_slice(&nonce), entropy)
+        .map_err(|_| anyhow!("failed to encrypt seed"))?;
+
+    Ok(EncryptedSeed {
+        m_cost: KDF_M_COST,
+        t_cost: KDF_T_COST,
+        p_cost: KDF_P_COST,
+        salt,
+        nonce,
+        ciphertext,
+    })
+}
+
+/// Decrypts the seed entropy, failing on a wrong passphrase
+pub fn decrypt(seed: &EncryptedSeed, passphrase: &str) -> Result<Vec<u8>> {
+    if seed.nonce.len() != 12 {
+        return Err(anyhow!("encrypted seed is corrupted"));
+    }
+
+    let key = derive_key(
+        passphrase,
+        &seed.salt,
+        seed.m_cost.min(MAX_M_COST),
+        seed.t_cost.min(MAX_T_COST),
+        seed.p_cost.min(MAX_P_COST),
+    )?;
+
+    // The AEAD tag doesn't tell a wrong passphrase from a corrupted record, the former is far likelier
+    ChaCha20Poly1305::new(&key)
+        .decrypt(Nonce::from_slice(&seed.nonce), seed.ciphertext.as_slice())
+        .map_err(|_| anyhow!("Wrong passphrase"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENTROPY: [u8; 16] = [7; 16];
+
+    #[test]
+    fn decrypts_with_the_passphrase_it_was_encrypted_with() {
+        let seed = encrypt(&ENTROPY, "correct horse").unwrap();
+
+        assert_ne!(seed.ciphertext, ENTROPY);
+        assert_eq!(decrypt(&seed, "correct horse").unwrap(), ENTROPY);
+    }
+
+    #[test]
+    fn rejects_a_wrong_passphrase() {
+        let seed = encrypt(&ENTROPY, "correct horse").unwrap();
+        let error = decrypt(&seed, "battery staple").unwrap_err();
+
+        assert_eq!(error.to_string(), "Wrong passphrase");
+    }
+
+    #[test]
+    fn rejects_a_malformed_nonce() {
+        let seed = EncryptedSeed {
+            nonce: vec![0; 4],
+            ..encrypt(&ENTROPY, "correct horse").unwrap()
+        };
+
+        assert!(decrypt(&seed, "correct horse").is_err());
+    }
+}
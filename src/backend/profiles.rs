@@ -0,0 +1,148 @@
+use anyhow::{Context, Result, anyhow};
+use std::{
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// The profile a wallet from before profiles existed is moved into
+pub const DEFAULT_PROFILE: &str = "default";
+
+const MAX_NAME_LEN: usize = 32;
+
+fn data_dir() -> Result<PathBuf> {
+    let db_dir = dirs::data_local_dir().context("Could not locate local data dir")?;
+
+    Ok(db_dir.join("tuimint/"))
+}
+
+fn profiles_dir() -> Result<PathBuf> {
+    Ok(data_dir()?.join("profiles/"))
+}
+
+/// Where a profile keeps its seed database and a database per joined federation
+pub fn profile_dir(name: &str) -> Result<PathBuf> {
+    Ok(profiles_dir()?.join(name))
+}
+
+/// Profile names double as directory names, so they're kept to a safe set of characters
+pub fn validate_name(name: &str) -> Result<()> {
+    if name.is_empty() {
+        return Err(anyhow!("Profile name can't be empty"));
+    }
+
+    if name.chars().count() > MAX_NAME_LEN {
+        return Err(anyhow!(
+            "Profile name is limited to {MAX_NAME_LEN} characters"
+        ));
+    }
+
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(anyhow!(
+            "Profile names may only contain letters, digits, - and _"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Lists the profiles by name
+pub async fn list_profiles() -> Result<Vec<String>> {
+    let mut profiles = Vec::new();
+    let mut entries = match tokio::fs::read_dir(profiles_dir()?).await {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(profiles),
+        Err(e) => return Err(e.into()),
+    };
+
+    while let Some(entry) = entries.next_entry().await? {
+        if entry.file_type().await?.is_dir()
+            && let Some(name) = entry.file_name().to_str()
+        {
+            profiles.push(name.to_string());
+        }
+    }
+
+    profiles.sort();
+
+    Ok(profiles)
+}
+
+/// Before profiles, the seed and federation databases sat right in the data dir, they're moved
+/// into `default` once at startup
+pub async fn migrate_single_wallet() -> Result<()> {
+    let data_dir = data_dir()?;
+
+    if !tokio::fs::try_exists(data_dir.join("tuimint.db")).await? {
+        return Ok(());
+    }
+
+    let profile_dir = profile_dir(DEFAULT_PROFILE)?;
+    tokio::fs::create_dir_all(&profile_dir).await?;
+
+    let mut entries = tokio::fs::read_dir(&data_dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let name = entry.file_name();
+
+        // Everything else belongs to the wallet, including the archive of left federations
+        if name == "profiles" || name == "deleted" {
+            continue;
+        }
+
+        tokio::fs::rename(entry.path(), profile_dir.join(name))
+            .await
+            .context("failed to move wallet into the default profile")?;
+    }
+
+    Ok(())
+}
+
+pub async fn create_profile(name: &str) -> Result<()> {
+    validate_name(name)?;
+
+    let dir = profile_dir(name)?;
+    if tokio::fs::try_exists(&dir).await? {
+        return Err(anyhow!("A profile named {name} already exists"));
+    }
+
+    tokio::fs::create_dir_all(&dir).await?;
+
+    Ok(())
+}
+
+pub async fn rename_profile(from: &str, to: &str) -> Result<()> {
+    validate_name(from)?;
+    validate_name(to)?;
+
+    let to_dir = profile_dir(to)?;
+    if tokio::fs::try_exists(&to_dir).await? {
+        return Err(anyhow!("A profile named {to} already exists"));
+    }
+
+    tokio::fs::rename(profile_dir(from)?, to_dir)
+        .await
+        .context("failed to rename profile")?;
+
+    Ok(())
+}
+
+/// Moves a profile into `deleted/`, its seed and ecash stay recoverable from there
+pub async fn delete_profile(name: &str) -> Result<PathBuf> {
+    validate_name(name)?;
+
+    let deleted_path = data_dir()?.join("deleted/");
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let deleted = deleted_path.join(format!("{name}-{secs}"));
+
+    tokio::fs::create_dir_all(&deleted_path).await?;
+    tokio::fs::rename(profile_dir(name)?, &deleted)
+        .await
+        .context("failed to delete profile")?;
+
+    Ok(deleted)
+}
@@ -1,22 +1,239 @@
 use fedimint_client::{OperationId, RootSecret};
 use fedimint_cursed_redb::MemAndRedb;
-use futures::StreamExt;
-use serde::Serialize;
-use std::{str::FromStr, sync::Arc, time::Duration};
+use fedimint_ln_client::{
+    InternalPayState, LightningClientInit, LightningClientModule, LnPayState, LnReceiveState,
+    OutgoingLightningPayment,
+};
+use futures::{StreamExt, stream::BoxStream};
+use lightning_invoice::{
+    Bolt11Invoice, Bolt11InvoiceDescription, Bolt11InvoiceDescriptionRef, Description,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
 
 use anyhow::{Context, Result, anyhow};
-use fedimint_api_client::api::net::Connector;
-use fedimint_client::{Client, ClientBuilder, ClientHandleArc};
-use fedimint_core::{Amount, config::FederationId, db::Database, invite_code::InviteCode};
+use fedimint_api_client::api::{FederationApiExt, net::Connector};
+use fedimint_client::{
+    Client, ClientBuilder, ClientHandleArc,
+    backup::Metadata,
+    oplog::{ChronologicalOperationLogKey, OperationLogEntry},
+};
+use fedimint_core::{
+    Amount, NumPeers, PeerId,
+    bitcoin::{self, Address, address::NetworkUnchecked},
+    config::{ClientConfig, FederationId},
+    db::Database,
+    endpoint_constants::{SESSION_COUNT_ENDPOINT, VERSION_ENDPOINT},
+    invite_code::InviteCode,
+    module::{ApiRequestErased, SupportedApiVersionsSummary},
+    secp256k1::PublicKey,
+};
+use fedimint_ln_common::LightningGateway;
 use fedimint_mint_client::{
-    MintClientInit, MintClientModule, OOBNotes, SelectNotesWithAtleastAmount,
+    MintClientInit, MintClientModule, MintOperationMeta, MintOperationMetaVariant, OOBNotes,
+    ReissueExternalNotesState, SelectNotesWithAtleastAmount, SpendOOBState,
 };
-use fedimint_wallet_client::WalletClientInit;
+use fedimint_wallet_client::{DepositStateV2, WalletClientInit, WalletClientModule, WithdrawState};
+use fedimint_wallet_common::{PegOutFees, config::WalletClientConfig};
+
+use super::history::{self, HistoryEntry, OperationDetail, OperationUpdate, UpdateStream};
+
+/// What paying an invoice will cost, shown to the user before confirming
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvoiceQuote {
+    pub invoice: Bolt11Invoice,
+    pub amount: Amount,
+    pub payee: String,
+    pub description: String,
+    /// Gateway routing fee, not charged if the payee turns out to use the same federation
+    pub fee: Amount,
+    /// The gateway the fee was quoted for, the payment is routed through the same one
+    pub gateway: Option<LightningGateway>,
+}
+
+/// A lightning gateway registered with the federation
+#[derive(Debug, Clone, PartialEq)]
+pub struct GatewayInfo {
+    pub gateway_id: PublicKey,
+    pub alias: String,
+    pub api: String,
+    pub base_fee: Amount,
+    pub proportional_millionths: u32,
+    pub vetted: bool,
+    /// Time left until the registration expires, gateways that stop re-registering are offline
+    pub ttl: Duration,
+}
+
+/// How much to move on-chain when pegging out
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WithdrawAmount {
+    /// The whole balance, less the peg-out fee
+    All,
+    Exact(bitcoin::Amount),
+}
+
+/// A peg-out checked against the federation's network, with the fee it will cost
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithdrawQuote {
+    pub address: Address,
+    pub amount: bitcoin::Amount,
+    pub fees: PegOutFees,
+}
+
+/// A guardian of the federation and where its API is reached
+#[derive(Debug, Clone, PartialEq)]
+pub struct GuardianInfo {
+    pub peer_id: PeerId,
+    pub name: String,
+    pub url: String,
+}
+
+/// A module the federation runs, e.g. mint, wallet or ln
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleInfo {
+    pub instance_id: u16,
+    pub kind: String,
+    pub version: String,
+}
+
+/// What the client config says about a federation we've joined
+#[derive(Debug, Clone, PartialEq)]
+pub struct FederationInfo {
+    pub id: FederationId,
+    pub name: Option<String>,
+    /// Remaining meta fields, e.g. a welcome message or the max balance
+    pub meta: Vec<(String, String)>,
+    pub guardians: Vec<GuardianInfo>,
+    /// Guardians that have to agree for consensus
+    pub threshold: usize,
+    pub modules: Vec<ModuleInfo>,
+    pub consensus_version: String,
+    pub network: Option<String>,
+    /// The invite code we joined with, filled in by the client handle
+    pub invite_code: Option<String>,
+}
+
+impl FederationInfo {
+    /// Summarizes a client config, either a joined federation's or one downloaded for a preview
+    fn from_config(id: FederationId, config: &ClientConfig) -> FederationInfo {
+        let global = &config.global;
+
+        let guardians = global
+            .api_endpoints
+            .iter()
+            .map(|(peer_id, endpoint)| GuardianInfo {
+                peer_id: *peer_id,
+                name: endpoint.name.clone(),
+                url: endpoint.url.to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        let modules = config
+            .modules
+            .iter()
+            .map(|(instance_id, module)| ModuleInfo {
+                instance_id: *instance_id,
+                kind: module.kind.to_string(),
+                version: format!("{}.{}", module.version.major, module.version.minor),
+            })
+            .collect();
+
+        let meta = global
+            .meta
+            .iter()
+            .filter(|(key, _)| key.as_str() != "federation_name")
+            .map(|(key, value)| {
+                // Meta values are JSON, unwrap plain strings so they don't show quoted
+                let value = serde_json::from_str::<String>(value).unwrap_or(value.clone());
+                (key.clone(), value)
+            })
+            .collect();
+
+        // Read from the module config rather than the module, a preview has no modules running
+        let network = config
+            .modules
+            .values()
+            .find(|module| module.kind == fedimint_wallet_common::KIND)
+            .and_then(|module| module.cast::<WalletClientConfig>().ok())
+            .map(|wallet| wallet.network.0.to_string());
+
+        FederationInfo {
+            id,
+            name: global.federation_name().map(|name| name.to_string()),
+            meta,
+            threshold: NumPeers::from(guardians.len()).threshold(),
+            guardians,
+            modules,
+            consensus_version: format!(
+                "{}.{}",
+                global.consensus_version.major, global.consensus_version.minor
+            ),
+            network,
+            invite_code: None,
+        }
+    }
+}
+
+/// How long a guardian gets to answer a health check before it counts as unreachable
+const GUARDIAN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// What a guardian answered to the last health check
+#[derive(Debug, Clone, PartialEq)]
+pub struct GuardianHealth {
+    pub peer_id: PeerId,
+    pub name: String,
+    /// Round trip of the session count request
+    pub latency: Option<Duration>,
+    pub session_count: Option<u64>,
+    pub consensus_version: Option<String>,
+    /// Why the guardian couldn't be reached, `None` if it answered
+    pub error: Option<String>,
+    pub checked: SystemTime,
+}
+
+impl GuardianHealth {
+    pub fn is_online(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// How long spent notes wait to be redeemed before the client takes them back
+pub const ECASH_SPEND_TIMEOUT: Duration = Duration::from_secs(60 * 60 * 24);
+
+/// Ecash we handed out that the recipient hasn't redeemed yet, as far as we know
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutgoingSpend {
+    pub operation_id: OperationId,
+    pub amount: Amount,
+    pub created: SystemTime,
+}
+
+/// Ecash notes someone handed to us, parsed so the user can check them before redeeming
+#[derive(Debug, Clone, PartialEq)]
+pub struct EcashPreview {
+    pub notes: String,
+    pub amount: Amount,
+    /// Only known if we've joined the federation or the notes carry an invite code
+    pub federation_id: Option<FederationId>,
+    /// Short id the notes are tagged with, shown when the full id is unknown
+    pub federation_prefix: String,
+    pub federation_name: Option<String>,
+    pub joined: bool,
+    /// Invite code bundled with the notes, lets the recipient join the federation
+    pub invite_code: Option<String>,
+}
 
 #[derive(Debug, Clone)]
 pub struct Wallet {
     pub federation_id: FederationId,
     pub client: ClientHandleArc,
+    /// Gateway to route lightning payments through, picked automatically if unset
+    gateway_id: Option<PublicKey>,
     db: Database,
 }
 
@@ -30,31 +247,48 @@ impl Wallet {
         builder.with_connector(Connector::default());
         builder.with_module(MintClientInit);
         builder.with_module(WalletClientInit::default());
+        builder.with_module(LightningClientInit::default());
 
         Ok(builder)
     }
 
-    async fn load_database(federation_id: FederationId) -> Result<Database> {
-        if let Some(db_dir) = dirs::data_local_dir() {
-            let db_path = db_dir.join("tuimint/");
-            let db_file = db_path.join(format!("{}.db", federation_id.to_string()));
+    async fn load_database(dir: &Path, federation_id: FederationId) -> Result<Database> {
+        let db_file = dir.join(format!("{federation_id}.db"));
 
-            let _ = tokio::fs::create_dir_all(&db_path).await;
-            let cursed_db = MemAndRedb::new(db_file)
-                .await
-                .context("failed to initialize redb database")?;
-            let db = Database::new(cursed_db, Default::default());
+        let _ = tokio::fs::create_dir_all(dir).await;
+        let cursed_db = MemAndRedb::new(db_file)
+            .await
+            .context("failed to initialize redb database")?;
+        let db = Database::new(cursed_db, Default::default());
 
-            Ok(db)
-        } else {
-            Err(anyhow!("Could not locate local data dir"))
-        }
+        Ok(db)
+    }
+
+    /// Moves a federation's client database into `archive/`, where its ecash can still be recovered
+    pub async fn archive_database(dir: &Path, federation_id: FederationId) -> Result<PathBuf> {
+        let archive_path = dir.join("archive/");
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let archived = archive_path.join(format!("{federation_id}-{secs}.db"));
+
+        tokio::fs::create_dir_all(&archive_path).await?;
+        tokio::fs::rename(dir.join(format!("{federation_id}.db")), &archived)
+            .await
+            .context("failed to archive client database")?;
+
+        Ok(archived)
     }
 
-    pub async fn from_joined(invite_code: &InviteCode, secret: RootSecret) -> Result<Wallet> {
+    pub async fn from_joined(
+        dir: &Path,
+        invite_code: &InviteCode,
+        secret: RootSecret,
+    ) -> Result<Wallet> {
         let builder = Wallet::build().await?;
 
-        let db = Wallet::load_database(invite_code.federation_id()).await?;
+        let db = Wallet::load_database(dir, invite_code.federation_id()).await?;
         let client = builder
             .preview(&invite_code)
             .await?
@@ -65,22 +299,156 @@ impl Wallet {
             federation_id: client.federation_id(),
 
             client: Arc::new(client),
+            gateway_id: None,
+            db: db.clone(),
+        })
+    }
+
+    /// Joins a federation with a restored seed, rebuilding the ecash the seed held there
+    ///
+    /// Starts from the federation's latest ecash backup if there is one, recovery then
+    /// continues in the background, see `subscribe_recovery`
+    pub async fn from_recovered(
+        dir: &Path,
+        invite_code: &InviteCode,
+        secret: RootSecret,
+    ) -> Result<Wallet> {
+        let builder = Wallet::build().await?;
+
+        let db = Wallet::load_database(dir, invite_code.federation_id()).await?;
+        let preview = builder.preview(invite_code).await?;
+        let backup = preview
+            .download_backup_from_federation(secret.clone())
+            .await?;
+        let client = preview.recover(db.clone(), secret, backup).await?;
+
+        Ok(Wallet {
+            federation_id: client.federation_id(),
+
+            client: Arc::new(client),
+            gateway_id: None,
             db: db.clone(),
         })
     }
 
-    pub async fn from_opened(federation_id: FederationId, secret: RootSecret) -> Result<Wallet> {
+    /// Wraps an already running client, e.g. one stored in a `ClientHandle`
+    pub fn from_client(client: ClientHandleArc) -> Wallet {
+        Wallet {
+            federation_id: client.federation_id(),
+            db: client.db().clone(),
+            gateway_id: None,
+            client,
+        }
+    }
+
+    pub fn with_gateway(mut self, gateway_id: Option<PublicKey>) -> Wallet {
+        self.gateway_id = gateway_id;
+        self
+    }
+
+    pub async fn from_opened(
+        dir: &Path,
+        federation_id: FederationId,
+        secret: RootSecret,
+    ) -> Result<Wallet> {
         let builder = Wallet::build().await?;
-        let db = Wallet::load_database(federation_id).await?;
+        let db = Wallet::load_database(dir, federation_id).await?;
         let client = builder.open(db.clone(), secret).await?;
 
         Ok(Wallet {
             federation_id,
             client: Arc::new(client),
+            gateway_id: None,
             db: db.clone(),
         })
     }
 
+    pub async fn federation_name(&self) -> Option<String> {
+        self.client
+            .config()
+            .await
+            .global
+            .federation_name()
+            .map(|name| name.to_string())
+    }
+
+    /// Reads the federation's config as the client received it on joining
+    pub async fn federation_info(&self) -> FederationInfo {
+        FederationInfo::from_config(self.federation_id, &self.client.config().await)
+    }
+
+    /// Downloads a federation's config from its invite code without joining it
+    pub async fn preview(invite_code: &InviteCode) -> Result<FederationInfo> {
+        let preview = Wallet::build().await?.preview(invite_code).await?;
+
+        Ok(FederationInfo::from_config(
+            invite_code.federation_id(),
+            preview.config(),
+        ))
+    }
+
+    /// Asks every guardian directly for its session count and consensus version
+    pub async fn guardian_health(&self) -> Vec<GuardianHealth> {
+        let config = self.client.config().await;
+        let api = self.client.api();
+
+        let checks = config
+            .global
+            .api_endpoints
+            .iter()
+            .map(|(peer_id, endpoint)| async move {
+                let start = Instant::now();
+                let session_count = tokio::time::timeout(
+                    GUARDIAN_TIMEOUT,
+                    api.request_single_peer::<u64>(
+                        SESSION_COUNT_ENDPOINT.to_owned(),
+                        ApiRequestErased::default(),
+                        *peer_id,
+                    ),
+                )
+                .await;
+                let latency = start.elapsed();
+
+                let (session_count, latency, error) = match session_count {
+                    Ok(Ok(count)) => (Some(count), Some(latency), None),
+                    Ok(Err(e)) => (None, None, Some(e.to_string())),
+                    Err(_) => (None, None, Some("timed out".to_string())),
+                };
+
+                // No point waiting on the version of a guardian that didn't answer
+                let consensus_version = match error {
+                    Some(_) => None,
+                    None => tokio::time::timeout(
+                        GUARDIAN_TIMEOUT,
+                        api.request_single_peer::<SupportedApiVersionsSummary>(
+                            VERSION_ENDPOINT.to_owned(),
+                            ApiRequestErased::default(),
+                            *peer_id,
+                        ),
+                    )
+                    .await
+                    .ok()
+                    .and_then(Result::ok)
+                    .map(|versions| {
+                        let version = versions.core.core_consensus;
+                        format!("{}.{}", version.major, version.minor)
+                    }),
+                };
+
+                GuardianHealth {
+                    peer_id: *peer_id,
+                    name: endpoint.name.clone(),
+                    latency,
+                    session_count,
+                    consensus_version,
+                    error,
+                    checked: SystemTime::now(),
+                }
+            });
+
+        futures::future::join_all(checks).await
+    }
+
     pub async fn balance(&mut self) -> Result<Amount> {
         if let Some(balance) = self.client.get_balance().await {
             Ok(balance)
@@ -89,6 +457,34 @@ impl Wallet {
         }
     }
 
+    /// Streams the balance every time it changes, starting with the current one
+    pub async fn subscribe_balance(&self) -> BoxStream<'static, Amount> {
+        self.client.subscribe_balance_changes().await
+    }
+
+    /// Uploads an encrypted snapshot of our notes, which recovery starts from
+    pub async fn backup(&self) -> Result<()> {
+        self.client.backup_to_federation(Metadata::empty()).await
+    }
+
+    /// Whether modules are still rebuilding their state from the federation
+    pub fn is_recovering(&self) -> bool {
+        self.client.has_pending_recoveries()
+    }
+
+    /// Streams recovery progress of each module as `(module instance, complete, total)`
+    pub fn subscribe_recovery(&self) -> BoxStream<'static, (u16, u32, u32)> {
+        self.client
+            .subscribe_to_recovery_progress()
+            .map(|(module_id, progress)| (module_id, progress.complete, progress.total))
+            .boxed()
+    }
+
+    /// Resolves once every module has finished recovering
+    pub async fn wait_for_recovery(&self) -> Result<()> {
+        self.client.wait_for_all_recoveries().await
+    }
+
     pub async fn spend_ecash(&mut self, amount: Amount) -> Result<(OperationId, OOBNotes)> {
         let mint = self
             .client
@@ -98,35 +494,460 @@ impl Wallet {
         mint.spend_notes_with_selector(
             &SelectNotesWithAtleastAmount,
             amount,
-            Duration::from_secs(60 * 60 * 24),
+            ECASH_SPEND_TIMEOUT,
             true,
             NoMeta {},
         )
         .await
     }
 
-    pub async fn receive_ecash(&mut self, notes: &str) -> Result<Amount> {
+    /// Spends that haven't settled, including ones created in earlier sessions
+    pub async fn outgoing_spends(&self) -> Vec<OutgoingSpend> {
+        self.operations()
+            .await
+            .into_iter()
+            .filter(|(_, entry)| entry.operation_module_kind() == "mint")
+            // Settled spends have their final state cached in the log, one we can't decode included
+            .filter(|(_, entry)| {
+                entry
+                    .try_outcome::<SpendOOBState>()
+                    .is_ok_and(|outcome| outcome.is_none())
+            })
+            .filter_map(|(key, entry)| {
+                let meta = entry.try_meta::<MintOperationMeta>().ok()?;
+
+                match meta.variant {
+                    MintOperationMetaVariant::SpendOOB { .. } => Some(OutgoingSpend {
+                        operation_id: key.operation_id,
+                        amount: meta.amount,
+                        created: key.creation_time,
+                    }),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Tries to take back spent notes, which fails if the recipient already redeemed them
+    pub async fn reclaim_ecash(&self, operation_id: OperationId) -> Result<()> {
+        let mint = self
+            .client
+            .get_first_module::<MintClientModule>()
+            .context("failed to get mint module")?;
+
+        mint.try_cancel_spend_notes(operation_id).await;
+
+        Ok(())
+    }
+
+    /// Streams a spend until the recipient redeems the notes or we take them back
+    pub async fn subscribe_ecash_spend(
+        &self,
+        operation_id: OperationId,
+    ) -> Result<BoxStream<'static, SpendOOBState>> {
         let mint = self.client.get_first_module::<MintClientModule>()?;
 
-        let oob_notes = OOBNotes::from_str(notes)?;
-        let operation_id = mint
-            .reissue_external_notes(oob_notes.clone(), NoMeta {})
-            .await?;
+        Ok(mint
+            .subscribe_spend_notes(operation_id)
+            .await?
+            .into_stream())
+    }
+
+    /// The client's operation log, newest first, classified for display
+    pub async fn history(&self) -> Vec<HistoryEntry> {
+        self.operations()
+            .await
+            .into_iter()
+            .map(|(key, entry)| HistoryEntry::from_log(self.federation_id, key, &entry))
+            .collect()
+    }
+
+    /// A single operation with everything its log entry records
+    ///
+    /// The log entry doesn't record when it was created, so that comes from the history listing
+    pub async fn operation_detail(
+        &self,
+        operation_id: OperationId,
+        created: SystemTime,
+    ) -> Result<OperationDetail> {
+        let entry = self
+            .client
+            .operation_log()
+            .get_operation(operation_id)
+            .await
+            .context("operation not found")?;
+        let key = ChronologicalOperationLogKey {
+            creation_time: created,
+            operation_id,
+        };
+
+        Ok(OperationDetail::from_log(self.federation_id, key, &entry))
+    }
+
+    /// Streams an operation's state changes from the module that runs it
+    pub async fn subscribe_operation(
+        &self,
+        operation_id: OperationId,
+    ) -> Result<BoxStream<'static, OperationUpdate>> {
+        let entry = self
+            .client
+            .operation_log()
+            .get_operation(operation_id)
+            .await
+            .context("operation not found")?;
+
+        let updates = match history::update_stream(&entry).context("operation has no updates")? {
+            UpdateStream::EcashSpend => self
+                .subscribe_ecash_spend(operation_id)
+                .await?
+                .map(history::spend_update)
+                .boxed(),
+            UpdateStream::EcashReissue => self
+                .subscribe_ecash_receive(operation_id)
+                .await?
+                .map(history::reissue_update)
+                .boxed(),
+            UpdateStream::LightningPay => self
+                .subscribe_lightning_pay(operation_id)
+                .await?
+                .map(history::pay_update)
+                .boxed(),
+            UpdateStream::InternalPay => self
+                .subscribe_internal_pay(operation_id)
+                .await?
+                .map(history::internal_pay_update)
+                .boxed(),
+            UpdateStream::LightningReceive => self
+                .subscribe_lightning_receive(operation_id)
+                .await?
+                .map(history::receive_update)
+                .boxed(),
+            UpdateStream::Deposit => self
+                .subscribe_deposit(operation_id)
+                .await?
+                .map(history::deposit_update)
+                .boxed(),
+            UpdateStream::Withdraw => self
+                .subscribe_withdraw(operation_id)
+                .await?
+                .map(history::withdraw_update)
+                .boxed(),
+        };
+
+        Ok(updates)
+    }
+
+    /// Every entry in the client's operation log, newest first
+    async fn operations(&self) -> Vec<(ChronologicalOperationLogKey, OperationLogEntry)> {
+        const PAGE_SIZE: usize = 100;
+
+        let mut operations = Vec::new();
+        let mut last = None;
+
+        loop {
+            let page = self
+                .client
+                .operation_log()
+                .paginate_operations_rev(PAGE_SIZE, last)
+                .await;
+            let done = page.len() < PAGE_SIZE;
+
+            last = page.last().map(|(key, _)| *key);
+            operations.extend(page);
+
+            if done {
+                return operations;
+            }
+        }
+    }
+
+    /// Starts reissuing notes someone handed to us, so the sender can't spend them again
+    pub async fn receive_ecash(&mut self, notes: &str) -> Result<(OperationId, Amount)> {
+        let mint = self
+            .client
+            .get_first_module::<MintClientModule>()
+            .context("failed to get mint module")?;
+
+        let oob_notes =
+            OOBNotes::from_str(notes.trim()).map_err(|e| anyhow!("invalid ecash notes: {e}"))?;
+        let amount = oob_notes.total_amount();
+        let operation_id = mint.reissue_external_notes(oob_notes, NoMeta {}).await?;
+
+        Ok((operation_id, amount))
+    }
 
-        let mut updates = mint
+    /// Streams a reissue from submission until the new notes are issued
+    pub async fn subscribe_ecash_receive(
+        &self,
+        operation_id: OperationId,
+    ) -> Result<BoxStream<'static, ReissueExternalNotesState>> {
+        let mint = self.client.get_first_module::<MintClientModule>()?;
+
+        Ok(mint
             .subscribe_reissue_external_notes(operation_id)
             .await?
-            .into_stream();
+            .into_stream())
+    }
+
+    /// Allocates a fresh address for pegging bitcoin into the federation
+    pub async fn deposit_address(&mut self) -> Result<(OperationId, Address)> {
+        let wallet = self
+            .client
+            .get_first_module::<WalletClientModule>()
+            .context("failed to get wallet module")?;
+
+        let (operation_id, address, _tweak_idx) =
+            wallet.safe_allocate_deposit_address(NoMeta {}).await?;
+
+        Ok((operation_id, address))
+    }
+
+    /// Streams a deposit from waiting for the transaction until the ecash is claimed
+    pub async fn subscribe_deposit(
+        &self,
+        operation_id: OperationId,
+    ) -> Result<BoxStream<'static, DepositStateV2>> {
+        let wallet = self.client.get_first_module::<WalletClientModule>()?;
 
-        while let Some(update) = updates.next().await {
-            if let fedimint_mint_client::ReissueExternalNotesState::Failed(e) = update {
-                return Err(anyhow!("failed to reissue ecash notes: {e}"));
+        Ok(wallet.subscribe_deposit(operation_id).await?.into_stream())
+    }
+
+    /// Validates a peg-out and asks the federation what it will cost
+    pub async fn quote_withdraw(
+        &mut self,
+        address: &str,
+        amount: WithdrawAmount,
+    ) -> Result<WithdrawQuote> {
+        let balance = bitcoin::Amount::from_sat(self.balance().await?.msats / 1000);
+        let wallet = self
+            .client
+            .get_first_module::<WalletClientModule>()
+            .context("failed to get wallet module")?;
+
+        let network = wallet.get_network();
+        let address = Address::<NetworkUnchecked>::from_str(address.trim())
+            .map_err(|e| anyhow!("invalid bitcoin address: {e}"))?
+            .require_network(network)
+            .map_err(|_| anyhow!("address is not for the federation's network ({network})"))?;
+
+        let (amount, fees) = match amount {
+            WithdrawAmount::Exact(amount) => {
+                let fees = wallet.get_withdraw_fees(&address, amount).await?;
+
+                if amount + fees.amount() > balance {
+                    return Err(anyhow!("balance is too low to cover the amount and fee"));
+                }
+
+                (amount, fees)
+            }
+            WithdrawAmount::All => {
+                let fees = wallet.get_withdraw_fees(&address, balance).await?;
+                let amount = balance
+                    .checked_sub(fees.amount())
+                    .context("balance is too low to cover the peg-out fee")?;
+
+                (amount, fees)
             }
+        };
+
+        Ok(WithdrawQuote {
+            address,
+            amount,
+            fees,
+        })
+    }
+
+    /// Pegs out to an on-chain address using a previously quoted fee
+    pub async fn withdraw(&mut self, quote: &WithdrawQuote) -> Result<OperationId> {
+        let wallet = self
+            .client
+            .get_first_module::<WalletClientModule>()
+            .context("failed to get wallet module")?;
+
+        wallet
+            .withdraw(&quote.address, quote.amount, quote.fees, NoMeta {})
+            .await
+    }
+
+    /// Streams a peg-out until the federation has broadcast the transaction
+    pub async fn subscribe_withdraw(
+        &self,
+        operation_id: OperationId,
+    ) -> Result<BoxStream<'static, WithdrawState>> {
+        let wallet = self.client.get_first_module::<WalletClientModule>()?;
+
+        Ok(wallet
+            .subscribe_withdraw_updates(operation_id)
+            .await?
+            .into_stream())
+    }
+
+    /// Creates a BOLT11 invoice routed through one of the federation's gateways
+    pub async fn receive_lightning(
+        &mut self,
+        amount: Amount,
+        description: String,
+        expiry: Duration,
+    ) -> Result<(OperationId, Bolt11Invoice)> {
+        self.receive_lightning_with_meta(amount, description, expiry, OperationMeta::default())
+            .await
+    }
+
+    /// Like `receive_lightning`, recording where the payment came from in the operation log
+    pub async fn receive_lightning_with_meta(
+        &mut self,
+        amount: Amount,
+        description: String,
+        expiry: Duration,
+        meta: OperationMeta,
+    ) -> Result<(OperationId, Bolt11Invoice)> {
+        let ln = self
+            .client
+            .get_first_module::<LightningClientModule>()
+            .context("failed to get lightning module")?;
+
+        let gateway = ln.get_gateway(self.gateway_id, false).await?;
+        let description = Description::new(description)
+            .map_err(|e| anyhow!("invalid invoice description: {e}"))?;
+
+        let (operation_id, invoice, _preimage) = ln
+            .create_bolt11_invoice(
+                amount,
+                Bolt11InvoiceDescription::Direct(description),
+                Some(expiry.as_secs()),
+                meta,
+                gateway,
+            )
+            .await?;
+
+        Ok((operation_id, invoice))
+    }
+
+    /// Streams the state of an incoming lightning payment until it is claimed or canceled
+    pub async fn subscribe_lightning_receive(
+        &self,
+        operation_id: OperationId,
+    ) -> Result<BoxStream<'static, LnReceiveState>> {
+        let ln = self.client.get_first_module::<LightningClientModule>()?;
+
+        Ok(ln.subscribe_ln_receive(operation_id).await?.into_stream())
+    }
+
+    /// Decodes an invoice and works out the gateway fee for paying it
+    pub async fn quote_invoice(&self, invoice: &str) -> Result<InvoiceQuote> {
+        let invoice = Bolt11Invoice::from_str(invoice.trim())
+            .map_err(|e| anyhow!("invalid lightning invoice: {e}"))?;
+
+        if invoice.is_expired() {
+            return Err(anyhow!("invoice has expired"));
         }
 
-        Ok(oob_notes.total_amount())
+        let amount = invoice
+            .amount_milli_satoshis()
+            .map(Amount::from_msats)
+            .context("invoices without an amount are not supported")?;
+
+        let description = match invoice.description() {
+            Bolt11InvoiceDescriptionRef::Direct(description) => description.to_string(),
+            Bolt11InvoiceDescriptionRef::Hash(_) => String::new(),
+        };
+
+        let ln = self
+            .client
+            .get_first_module::<LightningClientModule>()
+            .context("failed to get lightning module")?;
+
+        let gateway = ln.get_gateway(self.gateway_id, false).await?;
+        let fee = match &gateway {
+            Some(gateway) => routing_fee(
+                gateway.fees.base_msat,
+                gateway.fees.proportional_millionths,
+                amount,
+            ),
+            None => Amount::ZERO,
+        };
+
+        Ok(InvoiceQuote {
+            payee: invoice.get_payee_pub_key().to_string(),
+            invoice,
+            amount,
+            description,
+            fee,
+            gateway,
+        })
+    }
+
+    /// Lists the gateways registered with the federation, refreshing the cached registrations first
+    pub async fn list_gateways(&self) -> Result<Vec<GatewayInfo>> {
+        let ln = self
+            .client
+            .get_first_module::<LightningClientModule>()
+            .context("failed to get lightning module")?;
+
+        ln.update_gateway_cache().await?;
+
+        Ok(ln
+            .list_gateways()
+            .await
+            .into_iter()
+            .map(|announcement| GatewayInfo {
+                gateway_id: announcement.info.gateway_id,
+                alias: announcement.info.lightning_alias,
+                api: announcement.info.api.to_string(),
+                base_fee: Amount::from_msats(u64::from(announcement.info.fees.base_msat)),
+                proportional_millionths: announcement.info.fees.proportional_millionths,
+                vetted: announcement.vetted,
+                ttl: announcement.ttl,
+            })
+            .collect())
+    }
+
+    /// Pays a quoted invoice, either internally or through the gateway the fee was quoted for
+    pub async fn pay_invoice(&mut self, quote: InvoiceQuote) -> Result<OutgoingLightningPayment> {
+        let ln = self
+            .client
+            .get_first_module::<LightningClientModule>()
+            .context("failed to get lightning module")?;
+
+        ln.pay_bolt11_invoice(quote.gateway, quote.invoice, NoMeta {})
+            .await
+    }
+
+    /// Streams the state of an outgoing payment routed through a gateway
+    pub async fn subscribe_lightning_pay(
+        &self,
+        operation_id: OperationId,
+    ) -> Result<BoxStream<'static, LnPayState>> {
+        let ln = self.client.get_first_module::<LightningClientModule>()?;
+
+        Ok(ln.subscribe_ln_pay(operation_id).await?.into_stream())
+    }
+
+    /// Streams the state of an outgoing payment to another user of the federation
+    pub async fn subscribe_internal_pay(
+        &self,
+        operation_id: OperationId,
+    ) -> Result<BoxStream<'static, InternalPayState>> {
+        let ln = self.client.get_first_module::<LightningClientModule>()?;
+
+        Ok(ln.subscribe_internal_pay(operation_id).await?.into_stream())
     }
 }
 
+/// Fee a gateway charges for routing `amount`
+fn routing_fee(base_msat: u32, proportional_millionths: u32, amount: Amount) -> Amount {
+    Amount::from_msats(
+        u64::from(base_msat) + amount.msats * u64::from(proportional_millionths) / 1_000_000,
+    )
+}
+
 #[derive(Serialize)]
 struct NoMeta {}
+
+/// Extra details stored with an operation in the client's operation log
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OperationMeta {
+    /// The LNURL an operation was started from, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lnurl: Option<String>,
+}
@@ -19,8 +19,27 @@ use backend::handle_messages;
 
 pub const FRAME_RATE: u64 = 30;
 
+/// How long the app can sit without input before it locks, if the seed has a passphrase
+pub const AUTO_LOCK_AFTER: Duration = Duration::from_secs(5 * 60);
+
 #[tokio::main]
 async fn main() {
+    // Both the TUI and headless commands only look for the wallet in profiles
+    if let Err(e) = backend::migrate_single_wallet().await {
+        eprintln!("Failed to move the wallet into the default profile: {e}");
+        std::process::exit(1);
+    }
+
+    // Headless commands, e.g. `tuimint export --format csv`
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("export") {
+        if let Err(e) = backend::export_command(&args[1..]).await {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
     // Enable mouse capture
     // execute!(std::io::stdout(), EnableMouseCapture).ok();
 
@@ -43,15 +62,28 @@ async fn main() {
 
     ratatui::run(|terminal| {
         let framerate = Duration::from_millis(1000 / FRAME_RATE);
+        let mut last_input = Instant::now();
 
         loop {
             let start = Instant::now();
 
+            // Only a passphrase can unlock the app, so setting one in Settings turns this on
+            if last_input.elapsed() >= AUTO_LOCK_AFTER {
+                let mut state = state.lock().unwrap();
+
+                if state.encrypted && state.screen != state::Screen::Unlock {
+                    state.lock_screen();
+                }
+            }
+
+            root.tick(&state);
             terminal.draw(|frame| root.render(frame, &state)).ok();
 
             if let Ok(true) = poll(Duration::ZERO)
                 && let Ok(event) = read()
             {
+                last_input = Instant::now();
+
                 if let Event::Key(key) = event
                     && key.code == KeyCode::Char('c')
                     && key.modifiers.contains(KeyModifiers::CONTROL)
@@ -1,6 +1,148 @@
+use crate::backend::{
+    ExportFilter, ExportFormat, InvoiceQuote, LnurlWithdrawRequest, PayRequest, WithdrawAmount,
+    WithdrawQuote,
+};
+use fedimint_core::{Amount, config::FederationId, core::OperationId, secp256k1::PublicKey};
+use std::{path::PathBuf, time::SystemTime};
+
 pub enum Message {
     /// Refreshes the list of fedimint clients
     RefreshClients,
     /// Refreshes the list of fedimint wallets for a given client
     RefreshWallets(String),
+    /// Creates a lightning invoice and waits for it to be paid
+    ReceiveLightning {
+        federation_id: FederationId,
+        amount: Amount,
+        description: String,
+    },
+    /// Decodes an invoice and quotes the fee for paying it,
+    /// LNURLs and lightning addresses are resolved to a pay request first
+    QuoteInvoice {
+        federation_id: FederationId,
+        invoice: String,
+    },
+    /// Requests an invoice from an LNURL-pay service and quotes it
+    RequestLnurlInvoice {
+        federation_id: FederationId,
+        request: PayRequest,
+        amount: Amount,
+        comment: String,
+    },
+    /// Pays a previously quoted invoice and tracks the payment
+    PayInvoice {
+        federation_id: FederationId,
+        quote: InvoiceQuote,
+    },
+    /// Downloads the config behind an invite code for the user to check before joining
+    PreviewFederation(String),
+    /// Joins a previously previewed federation
+    JoinFederation(String),
+    /// Backs up a federation's ecash to it right away
+    BackupNow(FederationId),
+    /// Opens a profile's wallet, closing the one currently open
+    SelectProfile(String),
+    /// Creates an empty profile, its seed is generated when it's first opened
+    CreateProfile(String),
+    RenameProfile {
+        from: String,
+        to: String,
+    },
+    /// Sets a profile aside, it can't be the open one
+    DeleteProfile(String),
+    /// Decrypts the seed with the passphrase entered at the prompt
+    Unlock(String),
+    /// Sets, changes or, with an empty `new`, removes the seed passphrase
+    SetPassphrase {
+        current: String,
+        new: String,
+    },
+    /// Loads the seed words for the user to write down
+    RevealSeed,
+    /// Records that the user wrote the seed down and passed the check
+    ConfirmSeedBackup,
+    /// Replaces the generated seed with the user's existing mnemonic,
+    /// an encrypted seed's `passphrase` is checked and kept for the restored one
+    RestoreSeed {
+        words: String,
+        passphrase: String,
+    },
+    /// Joins a federation with the restored seed and rebuilds its ecash
+    RecoverFederation(String),
+    /// Checks what leaving a federation would leave behind
+    CheckLeave(FederationId),
+    /// Leaves a federation, `force` leaves even with funds or operations still in it
+    LeaveFederation {
+        federation_id: FederationId,
+        force: bool,
+    },
+    /// Reads a federation's config for the detail screen
+    LoadFederation(FederationId),
+    /// Asks each of a federation's guardians whether it is up, outside the periodic check
+    CheckHealth(FederationId),
+    /// Lists the lightning gateways registered with a federation
+    ListGateways(FederationId),
+    /// Pins the gateway used for a federation, `None` goes back to automatic selection
+    SelectGateway {
+        federation_id: FederationId,
+        gateway_id: Option<PublicKey>,
+    },
+    /// Fetches the offer behind an LNURL-withdraw code
+    FetchLnurlWithdraw(String),
+    /// Redeems `amount` of an LNURL-withdraw offer into a federation
+    RedeemLnurlWithdraw {
+        federation_id: FederationId,
+        request: LnurlWithdrawRequest,
+        amount: Amount,
+    },
+    /// Allocates a fresh deposit address and follows the deposit until it is claimed
+    DepositAddress(FederationId),
+    /// Validates a peg-out address and quotes the federation's fee
+    QuoteWithdraw {
+        federation_id: FederationId,
+        address: String,
+        amount: WithdrawAmount,
+    },
+    /// Pegs out a previously quoted withdrawal and waits for the transaction id
+    Withdraw {
+        federation_id: FederationId,
+        quote: WithdrawQuote,
+    },
+    /// Spends ecash notes to hand over out of band
+    SpendEcash {
+        federation_id: FederationId,
+        amount: Amount,
+    },
+    /// Takes back spent ecash the recipient hasn't redeemed yet
+    ReclaimEcash {
+        federation_id: FederationId,
+        operation_id: OperationId,
+    },
+    /// Loads the operation history of every joined federation
+    LoadHistory,
+    /// Loads an operation's details and follows it while it is still in flight
+    WatchOperation {
+        federation_id: FederationId,
+        operation_id: OperationId,
+        /// As listed in the history, the log entry itself doesn't record it
+        created: SystemTime,
+    },
+    /// Labels an operation, an empty label removes it
+    SetLabel {
+        operation_id: OperationId,
+        label: String,
+    },
+    /// Writes the history of all federations to a file
+    ExportHistory {
+        format: ExportFormat,
+        filter: ExportFilter,
+        path: PathBuf,
+    },
+    /// Parses ecash notes and looks up their federation, without redeeming them
+    PreviewEcash(String),
+    /// Redeems previously previewed ecash notes into the federation they belong to
+    ReceiveEcash {
+        federation_id: FederationId,
+        notes: String,
+    },
 }
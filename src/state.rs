@@ -1,4 +1,18 @@
-use std::sync::{Arc, Mutex};
+use crate::backend::{
+    EcashPreview, FederationInfo, GatewayInfo, GuardianHealth, HistoryEntry, InvoiceQuote,
+    LeaveCheck, LnurlWithdrawRequest, OperationDetail, PayRequest, WithdrawQuote,
+};
+use fedimint_core::{
+    Amount, NumPeers, config::FederationId, core::OperationId, secp256k1::PublicKey,
+};
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+/// How old the last ecash backup of a federation can get before the user is warned
+pub const BACKUP_MAX_AGE: Duration = Duration::from_secs(60 * 60 * 24 * 7);
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Screen {
@@ -7,21 +21,597 @@ pub enum Screen {
     Join,
     Wallets,
     Settings,
+    Receive,
+    Send,
+    Gateways,
+    LnurlWithdraw,
+    Deposit,
+    PegOut,
+    SendEcash,
+    ReceiveEcash,
+    PendingEcash,
+    History,
+    Operation,
+    Export,
+    Federation,
+    Leave,
+    Restore,
+    Seed,
+    Unlock,
+    Passphrase,
+    Profiles,
+}
+
+/// A joined federation as shown in the UI
+#[derive(Debug, Clone)]
+pub struct FederationSummary {
+    pub id: FederationId,
+    pub name: Option<String>,
+    pub balance: Amount,
+}
+
+/// Progress of an incoming lightning payment
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum LightningReceive {
+    #[default]
+    Idle,
+    CreatingInvoice,
+    AwaitingPayment {
+        invoice: String,
+    },
+    Funded {
+        invoice: String,
+    },
+    Claimed {
+        amount: Amount,
+    },
+    Canceled(String),
+    Failed(String),
+}
+
+impl LightningReceive {
+    /// Whether this status tracks the given, still unpaid or unclaimed invoice
+    pub fn is_for_invoice(&self, invoice: &str) -> bool {
+        match self {
+            LightningReceive::AwaitingPayment { invoice: i }
+            | LightningReceive::Funded { invoice: i } => i == invoice,
+            _ => false,
+        }
+    }
+}
+
+/// Progress of an outgoing lightning payment
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum LightningSend {
+    #[default]
+    Idle,
+    Decoding,
+    /// An LNURL-pay request or lightning address waiting for the user to pick an amount
+    EnterAmount(PayRequest),
+    Confirm(InvoiceQuote),
+    Paying {
+        invoice: String,
+    },
+    /// The payment is locked in a contract and the gateway is routing it
+    Funded {
+        invoice: String,
+    },
+    Refunding {
+        invoice: String,
+        reason: String,
+    },
+    Succeeded {
+        amount: Amount,
+        fee: Amount,
+    },
+    Refunded(String),
+    Failed(String),
+}
+
+impl LightningSend {
+    /// Whether this status tracks the given invoice, while its payment is still in flight
+    pub fn is_for_invoice(&self, invoice: &str) -> bool {
+        match self {
+            LightningSend::Paying { invoice: i }
+            | LightningSend::Funded { invoice: i }
+            | LightningSend::Refunding { invoice: i, .. } => i == invoice,
+            _ => false,
+        }
+    }
+}
+
+/// Progress of redeeming an LNURL-withdraw code
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum LnurlWithdraw {
+    #[default]
+    Idle,
+    Fetching,
+    Confirm(LnurlWithdrawRequest),
+    CreatingInvoice,
+    Submitting,
+    AwaitingPayment,
+    Claimed {
+        amount: Amount,
+    },
+    Canceled(String),
+    Failed(String),
+}
+
+/// Progress of an on-chain deposit (peg-in)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Deposit {
+    #[default]
+    Idle,
+    Allocating,
+    WaitingForTransaction {
+        address: String,
+    },
+    /// The transaction is in the mempool or not yet deep enough
+    Seen {
+        address: String,
+        amount: Amount,
+        txid: String,
+    },
+    /// The federation saw enough confirmations and is issuing ecash
+    Confirmed {
+        address: String,
+        amount: Amount,
+        txid: String,
+    },
+    Claimed {
+        address: String,
+        amount: Amount,
+        txid: String,
+    },
+    Failed(String),
+}
+
+impl Deposit {
+    pub fn address(&self) -> Option<&str> {
+        match self {
+            Deposit::WaitingForTransaction { address }
+            | Deposit::Seen { address, .. }
+            | Deposit::Confirmed { address, .. }
+            | Deposit::Claimed { address, .. } => Some(address),
+            _ => None,
+        }
+    }
+}
+
+/// Progress of an on-chain withdrawal (peg-out)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum PegOut {
+    #[default]
+    Idle,
+    Quoting,
+    Confirm(WithdrawQuote),
+    Withdrawing,
+    Succeeded {
+        txid: String,
+    },
+    Failed(String),
+}
+
+/// Progress of spending ecash notes to hand to someone else
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum EcashSend {
+    #[default]
+    Idle,
+    Spending,
+    Spent {
+        amount: Amount,
+        notes: String,
+    },
+    Failed(String),
+}
+
+/// Progress of redeeming ecash notes someone handed to us
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum EcashReceive {
+    #[default]
+    Idle,
+    Parsing,
+    Confirm(EcashPreview),
+    /// The reissue transaction was submitted to the federation
+    Reissuing {
+        amount: Amount,
+    },
+    /// The federation accepted the old notes and is signing new ones
+    Issuing {
+        amount: Amount,
+    },
+    Received {
+        amount: Amount,
+    },
+    Failed(String),
+}
+
+impl EcashReceive {
+    /// Whether notes are being redeemed and updates are still expected
+    pub fn is_redeeming(&self) -> bool {
+        matches!(
+            self,
+            EcashReceive::Reissuing { .. } | EcashReceive::Issuing { .. }
+        )
+    }
+}
+
+/// Ecash we handed out that hasn't been redeemed or reclaimed yet
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingSpend {
+    pub federation_id: FederationId,
+    pub operation_id: OperationId,
+    pub amount: Amount,
+    pub created: SystemTime,
+    /// We asked for the notes back and are waiting on the federation
+    pub reclaiming: bool,
+}
+
+/// How the most recent pending spend settled
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpendNotice {
+    Reclaimed(Amount),
+    /// The recipient redeemed the notes before we could take them back
+    Redeemed(Amount),
+    Failed(String),
+}
+
+/// Progress of joining a federation from an invite code
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum JoinFederation {
+    #[default]
+    Idle,
+    Previewing,
+    Confirm(FederationInfo),
+    Joining(FederationInfo),
+    Joined(FederationInfo),
+    Failed(String),
+}
+
+/// Ecash backups of a federation, uploaded so the seed can recover its notes
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FederationBackup {
+    pub last: Option<SystemTime>,
+    pub running: bool,
+    /// Why the latest attempt failed, cleared by the next successful one
+    pub error: Option<String>,
+}
+
+/// The passphrase prompt shown while the seed is encrypted and locked
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Unlock {
+    #[default]
+    Idle,
+    Locked,
+    Unlocking,
+    Failed(String),
+}
+
+/// Progress of setting, changing or removing the seed passphrase
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum PassphraseChange {
+    #[default]
+    Idle,
+    Saving,
+    Saved,
+    Failed(String),
+}
+
+/// Progress of creating, renaming or deleting a profile
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum ProfileAction {
+    #[default]
+    Idle,
+    Working,
+    Done(String),
+    Failed(String),
+}
+
+/// Whether the user has confirmed they wrote their seed down
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum SeedBackup {
+    /// Not read from the database yet
+    #[default]
+    Unknown,
+    NotBackedUp,
+    BackedUp,
+}
+
+/// The seed words, only loaded while the user is writing them down
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum SeedReveal {
+    #[default]
+    Hidden,
+    Loading,
+    Revealed(Vec<String>),
+    Failed(String),
+}
+
+/// Progress of restoring a wallet from an existing seed
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Restore {
+    #[default]
+    Idle,
+    Restoring,
+    /// The seed is in place and federations can be recovered with it
+    Restored,
+    Failed(String),
+}
+
+/// Progress of rebuilding a federation's ecash from the restored seed
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecoveryStatus {
+    Joining,
+    /// Summed over all modules, in whatever unit the modules count their progress
+    Recovering {
+        complete: u32,
+        total: u32,
+    },
+    Done,
+    Failed(String),
+}
+
+impl RecoveryStatus {
+    /// Share of the work done, `None` until the modules have reported how much there is
+    pub fn percent(&self) -> Option<u32> {
+        match self {
+            RecoveryStatus::Recovering { complete, total } if *total > 0 => {
+                Some((u64::from(*complete) * 100 / u64::from(*total)) as u32)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A federation being recovered, shown on the Restore screen
+#[derive(Debug, Clone, PartialEq)]
+pub struct FederationRecovery {
+    pub federation_id: FederationId,
+    pub name: Option<String>,
+    pub status: RecoveryStatus,
+}
+
+/// Progress of leaving the selected federation
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum LeaveFederation {
+    #[default]
+    Idle,
+    Checking,
+    Confirm(LeaveCheck),
+    Leaving,
+    Left {
+        name: Option<String>,
+        archive: String,
+    },
+    Failed(String),
+}
+
+/// Config of the selected federation
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum FederationView {
+    #[default]
+    Idle,
+    Loading,
+    Loaded(FederationInfo),
+    Failed(String),
+}
+
+/// How many of a federation's guardians answered the last health check
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FederationHealth {
+    Online,
+    /// Some guardians are down, but enough remain to reach consensus
+    Degraded {
+        online: usize,
+        total: usize,
+    },
+    /// Too few guardians answered for the federation to make progress
+    Offline {
+        online: usize,
+        total: usize,
+    },
+}
+
+/// Gateways of the selected federation
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum GatewayList {
+    #[default]
+    Idle,
+    Loading,
+    Loaded {
+        gateways: Vec<GatewayInfo>,
+        preferred: Option<PublicKey>,
+    },
+    Failed(String),
+}
+
+/// Operations of all joined federations, newest first
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum History {
+    #[default]
+    Idle,
+    Loading,
+    /// `failed` says which federations' operations couldn't be read
+    Loaded {
+        entries: Vec<HistoryEntry>,
+        failed: Vec<String>,
+    },
+    Failed(String),
+}
+
+/// Progress of exporting the history to a file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Export {
+    #[default]
+    Idle,
+    Exporting,
+    Done {
+        path: String,
+        count: usize,
+    },
+    Failed(String),
+}
+
+/// The operation opened from the History screen
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum OperationView {
+    #[default]
+    Idle,
+    Loading(OperationId),
+    Loaded(OperationDetail),
+    Failed(String),
+}
+
+impl OperationView {
+    pub fn operation_id(&self) -> Option<OperationId> {
+        match self {
+            OperationView::Loading(operation_id) => Some(*operation_id),
+            OperationView::Loaded(detail) => Some(detail.entry.operation_id),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct AppState {
     pub screen: Screen,
+    pub profiles: Vec<String>,
+    /// The profile whose wallet is open, `None` while picking one
+    pub profile: Option<String>,
+    pub profile_action: ProfileAction,
     pub count: u64,
+    pub federations: Vec<FederationSummary>,
+    pub selected_federation: Option<FederationId>,
+    pub ln_receive: LightningReceive,
+    pub ln_send: LightningSend,
+    pub gateways: GatewayList,
+    pub join: JoinFederation,
+    pub leave: LeaveFederation,
+    pub restore: Restore,
+    pub seed_backup: SeedBackup,
+    pub unlock: Unlock,
+    /// The screen to return to once unlocked
+    pub locked_from: Option<Screen>,
+    /// Whether the seed is protected by a passphrase
+    pub encrypted: bool,
+    pub passphrase: PassphraseChange,
+    pub seed: SeedReveal,
+    pub backups: BTreeMap<FederationId, FederationBackup>,
+    pub recoveries: Vec<FederationRecovery>,
+    pub federation: FederationView,
+    /// Latest health check of each federation's guardians
+    pub guardian_health: BTreeMap<FederationId, Vec<GuardianHealth>>,
+    pub lnurl_withdraw: LnurlWithdraw,
+    pub deposit: Deposit,
+    pub peg_out: PegOut,
+    pub ecash_send: EcashSend,
+    pub ecash_receive: EcashReceive,
+    pub pending_spends: Vec<PendingSpend>,
+    pub spend_notice: Option<SpendNotice>,
+    pub history: History,
+    pub operation: OperationView,
+    pub export: Export,
+    pub error: Option<String>,
 }
 
 impl AppState {
     pub fn new() -> AppState {
         AppState {
             screen: Screen::Splash,
+            profiles: Vec::new(),
+            profile: None,
+            profile_action: ProfileAction::Idle,
             count: 0,
+            federations: Vec::new(),
+            selected_federation: None,
+            ln_receive: LightningReceive::Idle,
+            ln_send: LightningSend::Idle,
+            gateways: GatewayList::Idle,
+            join: JoinFederation::Idle,
+            leave: LeaveFederation::Idle,
+            restore: Restore::Idle,
+            seed_backup: SeedBackup::Unknown,
+            unlock: Unlock::Idle,
+            locked_from: None,
+            encrypted: false,
+            passphrase: PassphraseChange::Idle,
+            seed: SeedReveal::Hidden,
+            backups: BTreeMap::new(),
+            recoveries: Vec::new(),
+            federation: FederationView::Idle,
+            guardian_health: BTreeMap::new(),
+            lnurl_withdraw: LnurlWithdraw::Idle,
+            deposit: Deposit::Idle,
+            peg_out: PegOut::Idle,
+            ecash_send: EcashSend::Idle,
+            ecash_receive: EcashReceive::Idle,
+            pending_spends: Vec::new(),
+            spend_notice: None,
+            history: History::Idle,
+            operation: OperationView::Idle,
+            export: Export::Idle,
+            error: None,
         }
     }
+
+    /// Starts over with a profile's wallet, nothing of the previous profile is kept
+    pub fn open_profile(&mut self, profile: &str) {
+        *self = AppState {
+            profiles: std::mem::take(&mut self.profiles),
+            profile: Some(profile.to_string()),
+            ..AppState::new()
+        };
+    }
+
+    /// Combined balance of every joined federation
+    pub fn total_balance(&self) -> Amount {
+        self.federations
+            .iter()
+            .map(|f| f.balance)
+            .fold(Amount::ZERO, |total, balance| total + balance)
+    }
+
+    /// Sums up the last health check of a federation, `None` until one has finished
+    pub fn federation_health(&self, federation_id: FederationId) -> Option<FederationHealth> {
+        let guardians = self.guardian_health.get(&federation_id)?;
+        let total = guardians.len();
+        let online = guardians.iter().filter(|g| g.is_online()).count();
+
+        Some(if online == total {
+            FederationHealth::Online
+        } else if online >= NumPeers::from(total).threshold() {
+            FederationHealth::Degraded { online, total }
+        } else {
+            FederationHealth::Offline { online, total }
+        })
+    }
+
+    /// Whether a federation holds funds the seed alone might not recover
+    pub fn backup_is_stale(&self, federation_id: FederationId) -> bool {
+        let backup = self.backups.get(&federation_id);
+        let balance = self
+            .federations
+            .iter()
+            .find(|f| f.id == federation_id)
+            .map(|f| f.balance)
+            .unwrap_or(Amount::ZERO);
+
+        if backup.is_some_and(|backup| backup.error.is_some()) {
+            return true;
+        }
+
+        match backup.and_then(|backup| backup.last) {
+            Some(last) => last.elapsed().unwrap_or_default() > BACKUP_MAX_AGE,
+            None => balance > Amount::ZERO,
+        }
+    }
+
+    /// The federation currently selected on the Wallets screen
+    pub fn selected(&self) -> Option<&FederationSummary> {
+        self.federations
+            .iter()
+            .find(|f| Some(f.id) == self.selected_federation)
+    }
 }
 
 // Mutable methods
@@ -31,5 +621,25 @@ impl AppState {
         self.screen = screen;
         self
     }
-}
 
+    /// Hides everything behind the passphrase prompt, the backend keeps running meanwhile
+    ///
+    /// A revealed seed is hidden again, screens clear half typed secrets once they see the lock
+    pub fn lock_screen(&mut self) -> &mut Self {
+        if self.screen != Screen::Unlock {
+            self.locked_from = Some(self.screen);
+        }
+
+        self.seed = SeedReveal::Hidden;
+        self.unlock = Unlock::Locked;
+        self.navigate(Screen::Unlock)
+    }
+
+    /// Goes back to where the user was when the app locked
+    pub fn unlocked(&mut self) -> &mut Self {
+        let screen = self.locked_from.take().unwrap_or(Screen::Splash);
+
+        self.unlock = Unlock::Idle;
+        self.navigate(screen)
+    }
+}
@@ -36,6 +36,11 @@ pub trait Component {
         Ok(())
     }
 
+    /// Advances animations, called once per frame
+    fn on_tick(&mut self, state: &AppStateMutex) {
+        let _ = state;
+    }
+
     /// Draws the Component and all its children
     fn render(&mut self, frame: &mut Frame, state: &AppStateMutex) {
         self.draw(frame, state);
@@ -45,6 +50,15 @@ pub trait Component {
         }
     }
 
+    /// Ticks the Component and all its children
+    fn tick(&mut self, state: &AppStateMutex) {
+        self.on_tick(state);
+
+        for child in self.children() {
+            child.tick(state);
+        }
+    }
+
     /// Handles terminal events
     fn handle_event(&mut self, event: Event, state: &AppStateMutex, tx: TxSender) -> Result<()> {
         for child in self.children() {
@@ -0,0 +1,46 @@
+use crate::backend::civil_date;
+use fedimint_core::Amount;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Formats an amount as whole sats, keeping the msat remainder if there is one
+pub fn sats(amount: Amount) -> String {
+    let msats = amount.msats;
+
+    if msats % 1000 == 0 {
+        format!("{} sats", msats / 1000)
+    } else {
+        format!("{}.{:03} sats", msats / 1000, msats % 1000)
+    }
+}
+
+/// Formats a duration coarsely, e.g. `3d 4h`, `2h 15m` or `40s`
+pub fn duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (days, hours, minutes) = (secs / 86_400, secs / 3600 % 24, secs / 60 % 60);
+
+    if days > 0 {
+        format!("{days}d {hours}h")
+    } else if hours > 0 {
+        format!("{hours}h {minutes}m")
+    } else if minutes > 0 {
+        format!("{minutes}m")
+    } else {
+        format!("{secs}s")
+    }
+}
+
+/// Formats a point in time as a UTC date and time, e.g. `2024-05-01 13:37:05`
+pub fn timestamp(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (year, month, day) = civil_date(secs / 86_400);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02}",
+        secs / 3600 % 24,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
@@ -1,12 +1,16 @@
 mod component;
+mod format;
 mod root;
 mod screens;
+mod widgets;
 
 pub use component::Component;
 pub use root::Root;
 
 pub mod prelude {
     pub use super::Component;
+    pub use super::format;
+    pub use super::widgets::*;
     pub use crate::message::Message;
     pub use crate::types::*;
     pub use crossterm::event::{KeyEvent, MouseEvent, KeyCode};
@@ -1,5 +1,7 @@
 use super::prelude::*;
 use crate::ui::screens::*;
+use anyhow::Result;
+use crossterm::event::Event;
 
 pub struct Root {
     children: Vec<Box<dyn Component>>,
@@ -13,9 +15,28 @@ impl Root {
             children: vec![
                 Box::new(SplashScreen::new()),
                 Box::new(TutorialScreen {}),
-                Box::new(JoinScreen {}),
+                Box::new(JoinScreen::new()),
                 Box::new(WalletsScreen {}),
                 Box::new(SettingsScreen {}),
+                Box::new(ReceiveScreen::new()),
+                Box::new(SendScreen::new()),
+                Box::new(GatewaysScreen::new()),
+                Box::new(LnurlWithdrawScreen::new()),
+                Box::new(DepositScreen::new()),
+                Box::new(PegOutScreen::new()),
+                Box::new(SendEcashScreen::new()),
+                Box::new(ReceiveEcashScreen::new()),
+                Box::new(PendingEcashScreen::new()),
+                Box::new(HistoryScreen::new()),
+                Box::new(OperationScreen::new()),
+                Box::new(ExportScreen::new()),
+                Box::new(FederationScreen::new()),
+                Box::new(LeaveScreen {}),
+                Box::new(RestoreScreen::new()),
+                Box::new(SeedScreen::new()),
+                Box::new(UnlockScreen::new()),
+                Box::new(PassphraseScreen::new()),
+                Box::new(ProfilesScreen::new()),
             ],
         }
     }
@@ -25,4 +46,19 @@ impl Component for Root {
     fn children(&mut self) -> &mut [Box<dyn Component>] {
         &mut self.children
     }
+
+    fn handle_event(&mut self, event: Event, state: &AppStateMutex, tx: TxSender) -> Result<()> {
+        let screen = state.lock().unwrap().screen;
+
+        for child in self.children() {
+            child.handle_event(event.clone(), state, tx.clone())?;
+
+            // Stop once a screen navigates away, so the next one doesn't see the same key
+            if state.lock().unwrap().screen != screen {
+                break;
+            }
+        }
+
+        Ok(())
+    }
 }
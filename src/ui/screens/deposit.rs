@@ -0,0 +1,151 @@
+use crate::state::{Deposit, Screen};
+use crate::ui::prelude::*;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+pub struct DepositScreen {
+    /// The address's code, encoded once per address
+    qr: Option<Qr>,
+}
+
+impl DepositScreen {
+    pub fn new() -> Self {
+        Self { qr: None }
+    }
+}
+
+/// A checklist line for one stage of the deposit
+fn stage<'a>(label: &'a str, reached: bool) -> Line<'a> {
+    if reached {
+        Line::from(vec![
+            Span::from(" ✓ ").style(Color::Green),
+            Span::from(label),
+        ])
+    } else {
+        Line::from(vec![Span::from(" ○ "), Span::from(label)]).style(Color::DarkGray)
+    }
+}
+
+impl Component for DepositScreen {
+    fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        if state.screen != Screen::Deposit {
+            return;
+        }
+
+        let name = state
+            .selected()
+            .map(|f| f.name.clone().unwrap_or_else(|| f.id.to_string()))
+            .unwrap_or_default();
+        let balance = state
+            .selected()
+            .map(|f| format::sats(f.balance))
+            .unwrap_or_default();
+
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(format!(" Deposit bitcoin · {name} "))
+            .title(Line::from(format!(" {balance} ")).right_aligned())
+            .title_bottom(" [n] new address  [esc] back ")
+            .title_alignment(Alignment::Center)
+            .padding(Padding::uniform(1));
+
+        let mut lines = Vec::new();
+
+        match &state.deposit {
+            Deposit::Idle | Deposit::Allocating => {
+                lines.push(Line::from("Generating deposit address..."));
+            }
+            Deposit::Failed(error) => {
+                lines.push(Line::from(format!("Deposit failed: {error}")).style(Color::Red));
+            }
+            deposit => {
+                let address = deposit.address().unwrap_or_default();
+                let reached = match deposit {
+                    Deposit::WaitingForTransaction { .. } => 0,
+                    Deposit::Seen { .. } => 1,
+                    Deposit::Confirmed { .. } => 2,
+                    _ => 3,
+                };
+
+                lines.push(Line::from(vec![
+                    Span::from("Address  "),
+                    Span::from(address).style(Color::Yellow),
+                ]));
+                lines.push(Line::from(format!("URI      bitcoin:{address}")));
+                lines.push(Line::from(""));
+
+                if let Deposit::Seen { amount, txid, .. }
+                | Deposit::Confirmed { amount, txid, .. }
+                | Deposit::Claimed { amount, txid, .. } = deposit
+                {
+                    lines.push(Line::from(format!("Amount   {}", format::sats(*amount))));
+                    lines.push(Line::from(format!("Txid     {txid}")));
+                    lines.push(Line::from(""));
+                }
+
+                lines.push(stage("Waiting for transaction", true));
+                lines.push(stage("Transaction seen", reached >= 1));
+                lines.push(stage("Confirmed", reached >= 2));
+                lines.push(stage("Claimed", reached >= 3));
+            }
+        }
+
+        let area = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
+
+        match &self.qr {
+            Some(qr) => {
+                let [text, code] =
+                    Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).areas(area);
+
+                frame.render_widget(paragraph, text);
+                frame.render_widget(qr, code);
+            }
+            None => frame.render_widget(paragraph, area),
+        }
+    }
+
+    fn on_tick(&mut self, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        // Uppercase BIP21 URIs encode to a smaller code and are accepted by wallets
+        let data = state
+            .deposit
+            .address()
+            .map(|a| format!("BITCOIN:{a}").to_uppercase());
+
+        Qr::cache(&mut self.qr, data.as_deref());
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::Deposit {
+            return Ok(());
+        }
+
+        match event.code {
+            KeyCode::Char('n') => {
+                if let Some(federation_id) = state.selected_federation {
+                    state.deposit = Deposit::Allocating;
+                    tx.send(Message::DepositAddress(federation_id)).ok();
+                }
+            }
+            KeyCode::Esc => {
+                state.navigate(Screen::Wallets);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
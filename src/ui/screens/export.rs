@@ -0,0 +1,197 @@
+use crate::backend::{ExportFilter, ExportFormat, default_export_path};
+use crate::state::{Export, Screen};
+use crate::ui::prelude::*;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+    From,
+    To,
+    Path,
+}
+
+/// Exports the history of all federations to CSV or JSON
+pub struct ExportScreen {
+    format: ExportFormat,
+    from: TextInput,
+    to: TextInput,
+    path: TextInput,
+    focus: Field,
+    error: Option<String>,
+}
+
+impl ExportScreen {
+    pub fn new() -> Self {
+        Self {
+            format: ExportFormat::default(),
+            from: TextInput::new(),
+            to: TextInput::new(),
+            path: TextInput::new(),
+            focus: Field::From,
+            error: None,
+        }
+    }
+
+    fn focused(&mut self) -> &mut TextInput {
+        match self.focus {
+            Field::From => &mut self.from,
+            Field::To => &mut self.to,
+            Field::Path => &mut self.path,
+        }
+    }
+}
+
+impl Component for ExportScreen {
+    fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        if state.screen != Screen::Export {
+            return;
+        }
+
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(" Export history ")
+            .title_bottom(" [tab] switch field  [←→] format  [enter] export  [esc] back ")
+            .title_alignment(Alignment::Center);
+        let area = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        let [format, from, to, path, status] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Min(0),
+        ])
+        .areas(area);
+
+        let formats: Vec<Span> = [ExportFormat::Csv, ExportFormat::Json]
+            .into_iter()
+            .map(|f| {
+                let name = format!(" {} ", f.extension().to_uppercase());
+
+                if f == self.format {
+                    Span::from(name).reversed()
+                } else {
+                    Span::from(name)
+                }
+            })
+            .collect();
+        frame.render_widget(
+            Paragraph::new(Line::from([vec![Span::from("Format  ")], formats].concat()))
+                .block(Block::new().padding(Padding::horizontal(1))),
+            format,
+        );
+
+        self.from
+            .draw(frame, from, "From (YYYY-MM-DD)", self.focus == Field::From);
+        self.to
+            .draw(frame, to, "To (YYYY-MM-DD)", self.focus == Field::To);
+        self.path
+            .draw(frame, path, "File", self.focus == Field::Path);
+
+        let default_path = default_export_path(self.format)
+            .map(|path| path.display().to_string())
+            .unwrap_or_default();
+
+        let mut lines = match &state.export {
+            Export::Idle => vec![
+                Line::from("Leave the dates empty to export everything"),
+                Line::from(format!("Leave the file empty to write {default_path}")),
+            ],
+            Export::Exporting => vec![Line::from("Exporting...")],
+            Export::Done { path, count } => {
+                vec![
+                    Line::from(format!("Exported {count} operations to {path}"))
+                        .style(Color::Green),
+                ]
+            }
+            Export::Failed(error) => {
+                vec![Line::from(format!("Export failed: {error}")).style(Color::Red)]
+            }
+        };
+
+        if let Some(error) = &self.error {
+            lines.push(Line::from(""));
+            lines.push(Line::from(error.as_str()).style(Color::Red));
+        }
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .block(Block::new().padding(Padding::uniform(1))),
+            status,
+        );
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::Export {
+            return Ok(());
+        }
+
+        match event.code {
+            KeyCode::Esc => {
+                self.error = None;
+                state.export = Export::Idle;
+                state.navigate(Screen::History);
+            }
+            KeyCode::Tab => {
+                self.focus = match self.focus {
+                    Field::From => Field::To,
+                    Field::To => Field::Path,
+                    Field::Path => Field::From,
+                };
+            }
+            KeyCode::Left | KeyCode::Right => {
+                self.format = match self.format {
+                    ExportFormat::Csv => ExportFormat::Json,
+                    ExportFormat::Json => ExportFormat::Csv,
+                };
+            }
+            KeyCode::Enter => {
+                self.error = None;
+
+                let filter = match ExportFilter::from_dates(self.from.value(), self.to.value()) {
+                    Ok(filter) => filter,
+                    Err(e) => {
+                        self.error = Some(e.to_string());
+                        return Ok(());
+                    }
+                };
+
+                let path = match self.path.value().trim() {
+                    "" => default_export_path(self.format),
+                    path => Some(PathBuf::from(path)),
+                };
+
+                let Some(path) = path else {
+                    self.error = Some("Couldn't find a home directory, enter a file".into());
+                    return Ok(());
+                };
+
+                state.export = Export::Exporting;
+                tx.send(Message::ExportHistory {
+                    format: self.format,
+                    filter,
+                    path,
+                })
+                .ok();
+            }
+            _ => {
+                self.focused().on_key_event(event);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,292 @@
+use crate::state::{FederationHealth, FederationView, GatewayList, LeaveFederation, Screen};
+use crate::ui::prelude::*;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+use std::time::SystemTime;
+
+/// What we know about the selected federation, from its client config
+pub struct FederationScreen {
+    scroll: u16,
+    /// The invite code's code, encoded once per federation
+    qr: Option<Qr>,
+}
+
+impl FederationScreen {
+    pub fn new() -> Self {
+        Self {
+            scroll: 0,
+            qr: None,
+        }
+    }
+}
+
+impl Component for FederationScreen {
+    fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        if state.screen != Screen::Federation {
+            return;
+        }
+
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(" Federation ")
+            .title_bottom(
+                " [↑↓] scroll  [g] gateways  [b] back up  [r] refresh  [x] leave  [esc] back ",
+            )
+            .title_alignment(Alignment::Center);
+
+        let info = match &state.federation {
+            FederationView::Loaded(info) => info,
+            FederationView::Idle | FederationView::Loading => {
+                let paragraph = Paragraph::new("Loading federation...")
+                    .alignment(Alignment::Center)
+                    .block(block.padding(Padding::vertical(1)));
+                frame.render_widget(paragraph, frame.area());
+                return;
+            }
+            FederationView::Failed(error) => {
+                let paragraph = Paragraph::new(format!("Failed to load federation: {error}"))
+                    .style(Color::Red)
+                    .alignment(Alignment::Center)
+                    .wrap(Wrap { trim: false })
+                    .block(block.padding(Padding::vertical(1)));
+                frame.render_widget(paragraph, frame.area());
+                return;
+            }
+        };
+
+        let mut lines = vec![
+            Line::from(info.name.clone().unwrap_or("Unnamed federation".into())).bold(),
+            Line::from(""),
+            Line::from(format!("Id          {}", info.id)),
+            Line::from(format!(
+                "Network     {}",
+                info.network.as_deref().unwrap_or("unknown")
+            )),
+            Line::from(format!("Consensus   v{}", info.consensus_version)),
+            Line::from(format!(
+                "Threshold   {} of {} guardians",
+                info.threshold,
+                info.guardians.len()
+            )),
+        ];
+
+        let backup = state.backups.get(&info.id).cloned().unwrap_or_default();
+        let last_backup = match backup.last {
+            Some(last) => format!(
+                "{} ago",
+                format::duration(last.elapsed().unwrap_or_default())
+            ),
+            None => "never".to_string(),
+        };
+        let backup_style = match state.backup_is_stale(info.id) {
+            true => Style::new().red(),
+            false => Style::new(),
+        };
+
+        lines.push(Line::from(format!("Backup      {last_backup}")).style(backup_style));
+
+        if backup.running {
+            lines.push(Line::from("            backing up...").style(Color::Yellow));
+        } else if let Some(error) = &backup.error {
+            lines.push(Line::from(format!("            {error}")).style(Color::Red));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from("Guardians").bold());
+
+        for guardian in &info.guardians {
+            lines.push(Line::from(vec![
+                Span::from(format!("{:>3}  ", guardian.peer_id)).style(Color::DarkGray),
+                Span::from(format!("{}  ", guardian.name)),
+                Span::from(guardian.url.clone()).style(Color::Blue),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+
+        match state.guardian_health.get(&info.id) {
+            Some(guardians) => {
+                let checked = guardians
+                    .iter()
+                    .map(|g| g.checked)
+                    .max()
+                    .and_then(|checked| SystemTime::now().duration_since(checked).ok())
+                    .unwrap_or_default();
+
+                lines.push(Line::from(vec![
+                    Span::from("Health").bold(),
+                    Span::from(format!("  checked {} ago", format::duration(checked)))
+                        .style(Color::DarkGray),
+                ]));
+                lines.push(
+                    Line::from(format!(
+                        "{:>3}  {:<16}  {:<8}  {:>8}  {:>9}  {:>7}",
+                        "id", "name", "status", "latency", "sessions", "version"
+                    ))
+                    .style(Color::DarkGray),
+                );
+
+                for guardian in guardians {
+                    let (status, color) = match guardian.is_online() {
+                        true => ("online", Color::Green),
+                        false => ("offline", Color::Red),
+                    };
+
+                    let mut line = Line::from(vec![
+                        Span::from(format!("{:>3}  ", guardian.peer_id)).style(Color::DarkGray),
+                        Span::from(format!("{:<16.16}  ", guardian.name)),
+                        Span::from(format!("{status:<8}  ")).style(color),
+                        Span::from(format!(
+                            "{:>8}  {:>9}  {:>7}",
+                            guardian
+                                .latency
+                                .map(|latency| format!("{} ms", latency.as_millis()))
+                                .unwrap_or("-".into()),
+                            guardian
+                                .session_count
+                                .map(|count| count.to_string())
+                                .unwrap_or("-".into()),
+                            guardian.consensus_version.as_deref().unwrap_or("-"),
+                        )),
+                    ]);
+
+                    if let Some(error) = &guardian.error {
+                        line.push_span(Span::from(format!("  {error}")).style(Color::Red));
+                    }
+
+                    lines.push(line);
+                }
+
+                lines.push(match state.federation_health(info.id) {
+                    Some(FederationHealth::Degraded { online, total }) => Line::from(format!(
+                        "Degraded, {online} of {total} guardians online, payments may be slow"
+                    ))
+                    .style(Color::Yellow),
+                    Some(FederationHealth::Offline { online, total }) => Line::from(format!(
+                        "Offline, only {online} of {total} guardians online, the federation can't reach consensus"
+                    ))
+                    .style(Color::Red),
+                    _ => Line::from("All guardians online").style(Color::Green),
+                });
+            }
+            None => {
+                lines.push(Line::from("Health").bold());
+                lines.push(Line::from("Checking guardians...").style(Color::DarkGray));
+            }
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from("Modules").bold());
+
+        for module in &info.modules {
+            lines.push(Line::from(vec![
+                Span::from(format!("{:>3}  ", module.instance_id)).style(Color::DarkGray),
+                Span::from(format!("{}  ", module.kind)),
+                Span::from(format!("v{}", module.version)).style(Color::DarkGray),
+            ]));
+        }
+
+        if !info.meta.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from("Meta").bold());
+
+            for (key, value) in &info.meta {
+                lines.push(Line::from(vec![
+                    Span::from(format!("{key}: ")).style(Color::DarkGray),
+                    Span::from(value.clone()),
+                ]));
+            }
+        }
+
+        if let Some(invite_code) = &info.invite_code {
+            lines.push(Line::from(""));
+            lines.push(Line::from("Invite code").bold());
+            lines.push(Line::from(invite_code.clone()).style(Color::Yellow));
+        }
+
+        let area = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        let paragraph = Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .scroll((self.scroll, 0))
+            .block(Block::new().padding(Padding::uniform(1)));
+
+        match &self.qr {
+            Some(qr) => {
+                let [text, code] =
+                    Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).areas(area);
+
+                frame.render_widget(paragraph, text);
+                frame.render_widget(qr, code);
+            }
+            None => frame.render_widget(paragraph, area),
+        }
+    }
+
+    fn on_tick(&mut self, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        // Invite codes are uppercased for a smaller code, bech32m decodes either case
+        let data = match &state.federation {
+            FederationView::Loaded(info) => info.invite_code.as_ref().map(|i| i.to_uppercase()),
+            _ => None,
+        };
+
+        Qr::cache(&mut self.qr, data.as_deref());
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::Federation {
+            return Ok(());
+        }
+
+        let Some(federation_id) = state.selected_federation else {
+            return Ok(());
+        };
+
+        match event.code {
+            KeyCode::Up => {
+                self.scroll = self.scroll.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                self.scroll = self.scroll.saturating_add(1);
+            }
+            KeyCode::Char('g') => {
+                state.gateways = GatewayList::Loading;
+                tx.send(Message::ListGateways(federation_id)).ok();
+                state.navigate(Screen::Gateways);
+            }
+            KeyCode::Char('r') => {
+                state.federation = FederationView::Loading;
+                tx.send(Message::LoadFederation(federation_id)).ok();
+                tx.send(Message::CheckHealth(federation_id)).ok();
+            }
+            KeyCode::Char('b') => {
+                state.backups.entry(federation_id).or_default().running = true;
+                tx.send(Message::BackupNow(federation_id)).ok();
+            }
+            KeyCode::Char('x') => {
+                state.leave = LeaveFederation::Checking;
+                tx.send(Message::CheckLeave(federation_id)).ok();
+                state.navigate(Screen::Leave);
+            }
+            KeyCode::Esc => {
+                self.scroll = 0;
+                state.navigate(Screen::Wallets);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+use crate::state::{GatewayList, Screen};
+use crate::ui::prelude::*;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+pub struct GatewaysScreen {
+    selected: usize,
+}
+
+impl GatewaysScreen {
+    pub fn new() -> Self {
+        Self { selected: 0 }
+    }
+}
+
+impl Component for GatewaysScreen {
+    fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        if state.screen != Screen::Gateways {
+            return;
+        }
+
+        let name = state
+            .selected()
+            .map(|f| f.name.clone().unwrap_or_else(|| f.id.to_string()))
+            .unwrap_or_default();
+
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(format!(" Gateways · {name} "))
+            .title_bottom(" [↑↓] select  [enter] pin  [c] automatic  [r] refresh  [esc] back ")
+            .title_alignment(Alignment::Center);
+
+        let (gateways, preferred) = match &state.gateways {
+            GatewayList::Loaded {
+                gateways,
+                preferred,
+            } => (gateways, preferred),
+            GatewayList::Idle | GatewayList::Loading => {
+                let paragraph = Paragraph::new("Loading gateways...")
+                    .alignment(Alignment::Center)
+                    .block(block.padding(Padding::vertical(1)));
+                frame.render_widget(paragraph, frame.area());
+                return;
+            }
+            GatewayList::Failed(error) => {
+                let paragraph = Paragraph::new(format!("Failed to load gateways: {error}"))
+                    .style(Color::Red)
+                    .alignment(Alignment::Center)
+                    .wrap(Wrap { trim: false })
+                    .block(block.padding(Padding::vertical(1)));
+                frame.render_widget(paragraph, frame.area());
+                return;
+            }
+        };
+
+        if gateways.is_empty() {
+            let paragraph = Paragraph::new("This federation has no registered gateways")
+                .alignment(Alignment::Center)
+                .block(block.padding(Padding::vertical(1)));
+            frame.render_widget(paragraph, frame.area());
+            return;
+        }
+
+        let rows = gateways.iter().map(|gateway| {
+            let pinned = if Some(gateway.gateway_id) == *preferred {
+                "★"
+            } else {
+                " "
+            };
+            let fees = format!(
+                "{} + {} ppm",
+                format::sats(gateway.base_fee),
+                gateway.proportional_millionths
+            );
+            let vetted = if gateway.vetted { "yes" } else { "no" };
+            let status = if gateway.ttl.is_zero() {
+                Span::from("expired").style(Color::Red)
+            } else {
+                Span::from(format!("online, {}m left", gateway.ttl.as_secs() / 60))
+                    .style(Color::Green)
+            };
+
+            Row::new(vec![
+                Cell::from(pinned).style(Color::Yellow),
+                Cell::from(gateway.alias.clone()),
+                Cell::from(fees),
+                Cell::from(vetted),
+                Cell::from(status),
+            ])
+        });
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(1),
+                Constraint::Fill(1),
+                Constraint::Length(22),
+                Constraint::Length(6),
+                Constraint::Length(18),
+            ],
+        )
+        .header(Row::new(vec!["", "Alias", "Fees", "Vetted", "Status"]).bold())
+        .row_highlight_style(Style::new().reversed())
+        .block(block);
+
+        let mut table_state = TableState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(table, frame.area(), &mut table_state);
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::Gateways {
+            return Ok(());
+        }
+
+        let Some(federation_id) = state.selected_federation else {
+            return Ok(());
+        };
+
+        let gateways = match &state.gateways {
+            GatewayList::Loaded { gateways, .. } => gateways.as_slice(),
+            _ => &[],
+        };
+
+        match event.code {
+            KeyCode::Up => {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                self.selected = (self.selected + 1).min(gateways.len().saturating_sub(1));
+            }
+            KeyCode::Enter => {
+                if let Some(gateway) = gateways.get(self.selected) {
+                    tx.send(Message::SelectGateway {
+                        federation_id,
+                        gateway_id: Some(gateway.gateway_id),
+                    })
+                    .ok();
+                }
+            }
+            KeyCode::Char('c') => {
+                tx.send(Message::SelectGateway {
+                    federation_id,
+                    gateway_id: None,
+                })
+                .ok();
+            }
+            KeyCode::Char('r') => {
+                tx.send(Message::ListGateways(federation_id)).ok();
+            }
+            KeyCode::Esc => {
+                self.selected = 0;
+                state.navigate(Screen::Wallets);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
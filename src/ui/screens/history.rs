@@ -0,0 +1,202 @@
+use crate::backend::OperationStatus;
+use crate::state::{History, OperationView, Screen};
+use crate::ui::prelude::*;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+/// Rows skipped by page up and page down
+const PAGE: usize = 10;
+
+/// Everything the wallet did, across all joined federations
+pub struct HistoryScreen {
+    selected: usize,
+}
+
+impl HistoryScreen {
+    pub fn new() -> Self {
+        Self { selected: 0 }
+    }
+}
+
+impl Component for HistoryScreen {
+    fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        if state.screen != Screen::History {
+            return;
+        }
+
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(" History ")
+            .title_bottom(
+                " [↑↓] select  [pgup/pgdn] page  [enter] details  [x] export  [r] refresh  [esc] back ",
+            )
+            .title_alignment(Alignment::Center);
+
+        let (entries, failed) = match &state.history {
+            History::Loaded { entries, failed } => (entries, failed),
+            History::Idle | History::Loading => {
+                let paragraph = Paragraph::new("Loading history...")
+                    .alignment(Alignment::Center)
+                    .block(block.padding(Padding::vertical(1)));
+                frame.render_widget(paragraph, frame.area());
+                return;
+            }
+            History::Failed(error) => {
+                let paragraph = Paragraph::new(format!("Failed to load history: {error}"))
+                    .style(Color::Red)
+                    .alignment(Alignment::Center)
+                    .wrap(Wrap { trim: false })
+                    .block(block.padding(Padding::vertical(1)));
+                frame.render_widget(paragraph, frame.area());
+                return;
+            }
+        };
+
+        if entries.is_empty() && failed.is_empty() {
+            let paragraph = Paragraph::new("No operations yet")
+                .alignment(Alignment::Center)
+                .block(block.padding(Padding::vertical(1)));
+            frame.render_widget(paragraph, frame.area());
+            return;
+        }
+
+        let rows = entries.iter().map(|entry| {
+            let federation = state
+                .federations
+                .iter()
+                .find(|f| f.id == entry.federation_id)
+                .and_then(|f| f.name.clone())
+                .unwrap_or_else(|| entry.federation_id.to_string());
+            let amount = match entry.amount {
+                Some(amount) if entry.kind.is_outgoing() => {
+                    Span::from(format!("-{}", format::sats(amount))).style(Color::Red)
+                }
+                Some(amount) => {
+                    Span::from(format!("+{}", format::sats(amount))).style(Color::Green)
+                }
+                None => Span::from("-"),
+            };
+            let fee = entry.fee.map(format::sats).unwrap_or_default();
+            let status = match &entry.status {
+                OperationStatus::Pending => Span::from("pending").style(Color::Yellow),
+                OperationStatus::Complete => Span::from("complete"),
+                OperationStatus::Refunded => Span::from("refunded").style(Color::Yellow),
+                OperationStatus::Failed(_) => Span::from("failed").style(Color::Red),
+            };
+
+            Row::new(vec![
+                Cell::from(format::timestamp(entry.time)),
+                Cell::from(federation),
+                Cell::from(entry.kind.to_string()),
+                Cell::from(amount),
+                Cell::from(fee),
+                Cell::from(status),
+            ])
+        });
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(19),
+                Constraint::Fill(1),
+                Constraint::Length(19),
+                Constraint::Length(18),
+                Constraint::Length(14),
+                Constraint::Length(9),
+            ],
+        )
+        .header(
+            Row::new(vec![
+                "Time (UTC)",
+                "Federation",
+                "Kind",
+                "Amount",
+                "Fee",
+                "Status",
+            ])
+            .bold(),
+        )
+        .row_highlight_style(Style::new().reversed());
+
+        let area = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        let [table_area, failed_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(failed.len() as u16)])
+                .areas(area);
+
+        let mut table_state = TableState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(table, table_area, &mut table_state);
+
+        let failed = failed
+            .iter()
+            .map(|error| Line::from(format!("Couldn't read {error}")).style(Color::Red));
+        frame.render_widget(Paragraph::new(Text::from_iter(failed)), failed_area);
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::History {
+            return Ok(());
+        }
+
+        let last = match &state.history {
+            History::Loaded { entries, .. } => entries.len().saturating_sub(1),
+            _ => 0,
+        };
+
+        match event.code {
+            KeyCode::Up => {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                self.selected = (self.selected + 1).min(last);
+            }
+            KeyCode::PageUp => {
+                self.selected = self.selected.saturating_sub(PAGE);
+            }
+            KeyCode::PageDown => {
+                self.selected = (self.selected + PAGE).min(last);
+            }
+            KeyCode::Enter => {
+                let entry = match &state.history {
+                    History::Loaded { entries, .. } => entries.get(self.selected).cloned(),
+                    _ => None,
+                };
+
+                if let Some(entry) = entry {
+                    state.operation = OperationView::Loading(entry.operation_id);
+                    tx.send(Message::WatchOperation {
+                        federation_id: entry.federation_id,
+                        operation_id: entry.operation_id,
+                        created: entry.time,
+                    })
+                    .ok();
+                    state.navigate(Screen::Operation);
+                }
+            }
+            KeyCode::Char('x') => {
+                state.navigate(Screen::Export);
+            }
+            KeyCode::Char('r') => {
+                state.history = History::Loading;
+                tx.send(Message::LoadHistory).ok();
+            }
+            KeyCode::Esc => {
+                self.selected = 0;
+                state.navigate(Screen::Wallets);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
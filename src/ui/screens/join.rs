@@ -1,9 +1,72 @@
-use crate::state::Screen;
+use crate::backend::FederationInfo;
+use crate::state::{JoinFederation, Screen};
 use crate::ui::prelude::*;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
-pub struct JoinScreen {}
+/// Joins a federation from an invite code, after showing the user what they're joining
+pub struct JoinScreen {
+    invite_code: TextInput,
+}
+
+impl JoinScreen {
+    pub fn new() -> Self {
+        Self {
+            invite_code: TextInput::new(),
+        }
+    }
+
+    fn preview_lines(info: &FederationInfo) -> Vec<Line<'static>> {
+        let mut lines = vec![
+            Line::from(info.name.clone().unwrap_or("Unnamed federation".into())).bold(),
+            Line::from(format!("Id          {}", info.id)),
+            Line::from(format!(
+                "Network     {}",
+                info.network.as_deref().unwrap_or("unknown")
+            )),
+            Line::from(format!("Consensus   v{}", info.consensus_version)),
+            Line::from(format!(
+                "Threshold   {} of {} guardians",
+                info.threshold,
+                info.guardians.len()
+            )),
+            Line::from(""),
+            Line::from("Guardians").bold(),
+        ];
+
+        for guardian in &info.guardians {
+            lines.push(Line::from(vec![
+                Span::from(format!("{:>3}  ", guardian.peer_id)).style(Color::DarkGray),
+                Span::from(format!("{}  ", guardian.name)),
+                Span::from(guardian.url.clone()).style(Color::Blue),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from("Modules").bold());
+        lines.push(Line::from(
+            info.modules
+                .iter()
+                .map(|module| format!("{} v{}", module.kind, module.version))
+                .collect::<Vec<_>>()
+                .join(", "),
+        ));
+
+        if !info.meta.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from("Meta").bold());
+
+            for (key, value) in &info.meta {
+                lines.push(Line::from(vec![
+                    Span::from(format!("{key}: ")).style(Color::DarkGray),
+                    Span::from(value.clone()),
+                ]));
+            }
+        }
+
+        lines
+    }
+}
 
 impl Component for JoinScreen {
     fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
@@ -13,8 +76,120 @@ impl Component for JoinScreen {
             return;
         }
 
-        let paragraph = ratatui::widgets::Paragraph::new("Join");
-        let block = Block::bordered().border_style(Style::new().red().on_white().bold().italic());
-        frame.render_widget(paragraph.clone().block(block), frame.area());
+        let keys = match state.join {
+            JoinFederation::Confirm(_) => " [y] join  [n] cancel ",
+            JoinFederation::Joined(_) => " [enter] wallets ",
+            JoinFederation::Joining(_) => "",
+            _ => " [enter] look up federation  [esc] back ",
+        };
+
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(" Join federation ")
+            .title_bottom(keys)
+            .title_alignment(Alignment::Center);
+        let area = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        let [invite_code, status] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+        let editable = matches!(state.join, JoinFederation::Idle | JoinFederation::Failed(_));
+        self.invite_code
+            .draw(frame, invite_code, "Invite code", editable);
+
+        let lines = match &state.join {
+            JoinFederation::Idle => vec![Line::from(
+                "Paste an invite code from the federation's guardians and press enter",
+            )],
+            JoinFederation::Previewing => vec![Line::from("Looking up federation...")],
+            JoinFederation::Confirm(info) => {
+                let mut lines = Self::preview_lines(info);
+                lines.push(Line::from(""));
+                lines.push(Line::from("Join this federation?").bold());
+                lines
+            }
+            JoinFederation::Joining(info) => {
+                let name = info.name.clone().unwrap_or(info.id.to_string());
+                vec![Line::from(format!("Joining {name}...")).style(Color::Yellow)]
+            }
+            JoinFederation::Joined(info) => {
+                let name = info.name.clone().unwrap_or(info.id.to_string());
+                vec![Line::from(format!("Joined {name}")).style(Color::Green)]
+            }
+            JoinFederation::Failed(error) => {
+                vec![Line::from(format!("Failed to join federation: {error}")).style(Color::Red)]
+            }
+        };
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .block(Block::new().padding(Padding::uniform(1))),
+            status,
+        );
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::Join {
+            return Ok(());
+        }
+
+        match &state.join {
+            JoinFederation::Confirm(info) => {
+                match event.code {
+                    KeyCode::Char('y') => {
+                        if let Some(invite_code) = &info.invite_code {
+                            tx.send(Message::JoinFederation(invite_code.clone())).ok();
+                        }
+                    }
+                    KeyCode::Char('n') | KeyCode::Esc => {
+                        state.join = JoinFederation::Idle;
+                    }
+                    _ => {}
+                }
+
+                return Ok(());
+            }
+            // Wait for the client to finish joining
+            JoinFederation::Previewing | JoinFederation::Joining(_) => return Ok(()),
+            JoinFederation::Joined(_) => {
+                if let KeyCode::Enter | KeyCode::Esc = event.code {
+                    self.invite_code.clear();
+                    state.join = JoinFederation::Idle;
+                    state.navigate(Screen::Wallets);
+                }
+
+                return Ok(());
+            }
+            JoinFederation::Idle | JoinFederation::Failed(_) => {}
+        }
+
+        match event.code {
+            KeyCode::Esc => {
+                self.invite_code.clear();
+                state.join = JoinFederation::Idle;
+                state.navigate(Screen::Wallets);
+            }
+            KeyCode::Enter if !self.invite_code.value().trim().is_empty() => {
+                state.join = JoinFederation::Previewing;
+                tx.send(Message::PreviewFederation(
+                    self.invite_code.value().trim().to_string(),
+                ))
+                .ok();
+            }
+            _ => {
+                self.invite_code.on_key_event(event);
+            }
+        }
+
+        Ok(())
     }
 }
@@ -0,0 +1,134 @@
+use crate::state::{LeaveFederation, Screen};
+use crate::ui::prelude::*;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+/// Confirms leaving the selected federation, with a warning if funds would be left behind
+pub struct LeaveScreen {}
+
+impl Component for LeaveScreen {
+    fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        if state.screen != Screen::Leave {
+            return;
+        }
+
+        let keys = match &state.leave {
+            LeaveFederation::Confirm(check) if check.is_safe() => " [y] leave  [n] cancel ",
+            LeaveFederation::Confirm(_) => " [f] leave anyway  [n] cancel ",
+            LeaveFederation::Left { .. } => " [enter] wallets ",
+            LeaveFederation::Checking | LeaveFederation::Leaving => "",
+            _ => " [esc] back ",
+        };
+
+        let block = Block::bordered()
+            .border_style(Style::new().red())
+            .title(" Leave federation ")
+            .title_bottom(keys)
+            .title_alignment(Alignment::Center);
+
+        let lines = match &state.leave {
+            LeaveFederation::Idle | LeaveFederation::Checking => {
+                vec![Line::from("Checking for funds and pending operations...")]
+            }
+            LeaveFederation::Confirm(check) => {
+                let name = check
+                    .name
+                    .clone()
+                    .unwrap_or(check.federation_id.to_string());
+
+                let mut lines = vec![
+                    Line::from(name).bold(),
+                    Line::from(""),
+                    Line::from(vec![
+                        Span::from("Balance     "),
+                        Span::from(format::sats(check.balance)).style(Color::Yellow),
+                    ]),
+                    Line::from(format!("Pending     {} operations", check.pending)),
+                    Line::from(""),
+                ];
+
+                if check.is_safe() {
+                    lines.push(Line::from("Nothing is left in this federation."));
+                } else {
+                    lines.push(
+                        Line::from(
+                            "Leaving now strands these funds, tuimint won't be able to spend them or finish pending operations.",
+                        )
+                        .style(Color::Red),
+                    );
+                }
+
+                lines.push(Line::from(
+                    "The client database is moved to the archive folder rather than deleted.",
+                ));
+                lines.push(Line::from(""));
+                lines.push(Line::from("Leave this federation?").bold());
+                lines
+            }
+            LeaveFederation::Leaving => vec![Line::from("Leaving federation...")],
+            LeaveFederation::Left { name, archive } => vec![
+                Line::from(format!(
+                    "Left {}",
+                    name.as_deref().unwrap_or("the federation")
+                ))
+                .style(Color::Green),
+                Line::from(""),
+                Line::from(format!("Client database archived to {archive}")),
+            ],
+            LeaveFederation::Failed(error) => {
+                vec![Line::from(format!("Failed to leave federation: {error}")).style(Color::Red)]
+            }
+        };
+
+        let paragraph = Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(block.padding(Padding::uniform(1)));
+        frame.render_widget(paragraph, frame.area());
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::Leave {
+            return Ok(());
+        }
+
+        match (&state.leave, event.code) {
+            (LeaveFederation::Confirm(check), KeyCode::Char('y')) if check.is_safe() => {
+                tx.send(Message::LeaveFederation {
+                    federation_id: check.federation_id,
+                    force: false,
+                })
+                .ok();
+                state.leave = LeaveFederation::Leaving;
+            }
+            (LeaveFederation::Confirm(check), KeyCode::Char('f')) if !check.is_safe() => {
+                tx.send(Message::LeaveFederation {
+                    federation_id: check.federation_id,
+                    force: true,
+                })
+                .ok();
+                state.leave = LeaveFederation::Leaving;
+            }
+            (LeaveFederation::Confirm(_), KeyCode::Char('n') | KeyCode::Esc)
+            | (LeaveFederation::Failed(_), KeyCode::Esc) => {
+                state.leave = LeaveFederation::Idle;
+                state.navigate(Screen::Federation);
+            }
+            (LeaveFederation::Left { .. }, KeyCode::Enter | KeyCode::Esc) => {
+                state.leave = LeaveFederation::Idle;
+                state.navigate(Screen::Wallets);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,184 @@
+use crate::state::{LnurlWithdraw, Screen};
+use crate::ui::prelude::*;
+use fedimint_core::Amount;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+/// Redeems LNURL-withdraw codes from faucets and vouchers
+pub struct LnurlWithdrawScreen {
+    lnurl: TextInput,
+    /// Left empty to take the most the service offers
+    amount: TextInput,
+}
+
+impl LnurlWithdrawScreen {
+    pub fn new() -> Self {
+        Self {
+            lnurl: TextInput::new(),
+            amount: TextInput::new(),
+        }
+    }
+}
+
+impl Component for LnurlWithdrawScreen {
+    fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        if state.screen != Screen::LnurlWithdraw {
+            return;
+        }
+
+        let name = state
+            .selected()
+            .map(|f| f.name.clone().unwrap_or_else(|| f.id.to_string()))
+            .unwrap_or_default();
+        let balance = state
+            .selected()
+            .map(|f| format::sats(f.balance))
+            .unwrap_or_default();
+
+        let keys = match state.lnurl_withdraw {
+            LnurlWithdraw::Confirm(_) => " [enter] redeem  [esc] cancel ",
+            _ => " [enter] look up  [esc] back ",
+        };
+
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(format!(" Redeem LNURL-withdraw · {name} "))
+            .title(Line::from(format!(" {balance} ")).right_aligned())
+            .title_bottom(keys)
+            .title_alignment(Alignment::Center);
+        let area = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        let [lnurl, status] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+        let editable = !matches!(state.lnurl_withdraw, LnurlWithdraw::Confirm(_));
+        self.lnurl.draw(frame, lnurl, "LNURL", editable);
+
+        let lines = match &state.lnurl_withdraw {
+            LnurlWithdraw::Idle => vec![Line::from("Paste an LNURL-withdraw code and press enter")],
+            LnurlWithdraw::Fetching => vec![Line::from("Looking up LNURL...")],
+            LnurlWithdraw::Confirm(request) => {
+                let [info, amount] =
+                    Layout::vertical([Constraint::Length(5), Constraint::Length(3)]).areas(status);
+
+                let lines = vec![
+                    Line::from(vec![
+                        Span::from("Amount       "),
+                        Span::from(format!(
+                            "{} to {}",
+                            format::sats(request.min_withdrawable),
+                            format::sats(request.max_withdrawable)
+                        ))
+                        .style(Color::Yellow),
+                    ]),
+                    Line::from(format!("Description  {}", request.description)),
+                    Line::from(""),
+                    Line::from(format!("Redeem into {name}?")).bold(),
+                ];
+                frame.render_widget(
+                    Paragraph::new(lines).block(Block::new().padding(Padding::horizontal(1))),
+                    info,
+                );
+
+                let title = format!(
+                    "Amount (sats, empty for {})",
+                    format::sats(request.max_withdrawable)
+                );
+                self.amount.draw(frame, amount, &title, true);
+                return;
+            }
+            LnurlWithdraw::CreatingInvoice => vec![Line::from("Creating invoice...")],
+            LnurlWithdraw::Submitting => vec![Line::from("Sending invoice to the service...")],
+            LnurlWithdraw::AwaitingPayment => {
+                vec![Line::from("Waiting for the service to pay...").style(Color::Yellow)]
+            }
+            LnurlWithdraw::Claimed { amount } => {
+                vec![Line::from(format!("Redeemed {}", format::sats(*amount))).style(Color::Green)]
+            }
+            LnurlWithdraw::Canceled(reason) => {
+                vec![Line::from(format!("Withdrawal canceled: {reason}")).style(Color::Red)]
+            }
+            LnurlWithdraw::Failed(error) => {
+                vec![Line::from(format!("Withdrawal failed: {error}")).style(Color::Red)]
+            }
+        };
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .block(Block::new().padding(Padding::uniform(1))),
+            status,
+        );
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::LnurlWithdraw {
+            return Ok(());
+        }
+
+        let Some(federation_id) = state.selected_federation else {
+            return Ok(());
+        };
+
+        if let LnurlWithdraw::Confirm(request) = &state.lnurl_withdraw {
+            match event.code {
+                KeyCode::Enter => {
+                    let amount = match self.amount.value().trim() {
+                        "" => Some(request.max_withdrawable),
+                        sats => sats.parse::<u64>().ok().map(Amount::from_sats),
+                    };
+
+                    // The handler checks the service's limits, we only need a number
+                    if let Some(amount) = amount {
+                        tx.send(Message::RedeemLnurlWithdraw {
+                            federation_id,
+                            request: request.clone(),
+                            amount,
+                        })
+                        .ok();
+                        state.lnurl_withdraw = LnurlWithdraw::CreatingInvoice;
+                        self.lnurl.clear();
+                        self.amount.clear();
+                    }
+                }
+                KeyCode::Esc => {
+                    state.lnurl_withdraw = LnurlWithdraw::Idle;
+                    self.amount.clear();
+                }
+                _ => {
+                    self.amount.on_key_event(event);
+                }
+            }
+
+            return Ok(());
+        }
+
+        match event.code {
+            KeyCode::Esc => {
+                state.navigate(Screen::Wallets);
+            }
+            KeyCode::Enter if !self.lnurl.value().is_empty() => {
+                state.lnurl_withdraw = LnurlWithdraw::Fetching;
+                tx.send(Message::FetchLnurlWithdraw(
+                    self.lnurl.value().trim().to_string(),
+                ))
+                .ok();
+            }
+            _ => {
+                self.lnurl.on_key_event(event);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,11 +1,49 @@
+mod deposit;
+mod export;
+mod federation;
+mod gateways;
+mod history;
 mod join;
+mod leave;
+mod lnurl_withdraw;
+mod operation;
+mod passphrase;
+mod peg_out;
+mod pending_ecash;
+mod profiles;
+mod receive;
+mod receive_ecash;
+mod restore;
+mod seed;
+mod send;
+mod send_ecash;
 mod settings;
 mod splash;
 mod tutorial;
+mod unlock;
 mod wallets;
 
+pub use deposit::DepositScreen;
+pub use export::ExportScreen;
+pub use federation::FederationScreen;
+pub use gateways::GatewaysScreen;
+pub use history::HistoryScreen;
 pub use join::JoinScreen;
+pub use leave::LeaveScreen;
+pub use lnurl_withdraw::LnurlWithdrawScreen;
+pub use operation::OperationScreen;
+pub use passphrase::PassphraseScreen;
+pub use peg_out::PegOutScreen;
+pub use pending_ecash::PendingEcashScreen;
+pub use profiles::ProfilesScreen;
+pub use receive::ReceiveScreen;
+pub use receive_ecash::ReceiveEcashScreen;
+pub use restore::RestoreScreen;
+pub use seed::SeedScreen;
+pub use send::SendScreen;
+pub use send_ecash::SendEcashScreen;
 pub use settings::SettingsScreen;
 pub use splash::SplashScreen;
 pub use tutorial::TutorialScreen;
+pub use unlock::UnlockScreen;
 pub use wallets::WalletsScreen;
@@ -0,0 +1,200 @@
+use crate::backend::OperationStatus;
+use crate::state::{OperationView, Screen};
+use crate::ui::prelude::*;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+/// A single operation from the History screen, updated live while it is in flight
+pub struct OperationScreen {
+    scroll: u16,
+    /// Set while the user is editing the operation's label
+    label: Option<TextInput>,
+}
+
+impl OperationScreen {
+    pub fn new() -> Self {
+        Self {
+            scroll: 0,
+            label: None,
+        }
+    }
+}
+
+impl Component for OperationScreen {
+    fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        if state.screen != Screen::Operation {
+            return;
+        }
+
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(" Operation ")
+            .title_bottom(if self.label.is_some() {
+                " [enter] save label  [esc] cancel "
+            } else {
+                " [↑↓] scroll  [l] label  [esc] back "
+            })
+            .title_alignment(Alignment::Center);
+
+        let detail = match &state.operation {
+            OperationView::Loaded(detail) => detail,
+            OperationView::Idle | OperationView::Loading(_) => {
+                let paragraph = Paragraph::new("Loading operation...")
+                    .alignment(Alignment::Center)
+                    .block(block.padding(Padding::vertical(1)));
+                frame.render_widget(paragraph, frame.area());
+                return;
+            }
+            OperationView::Failed(error) => {
+                let paragraph = Paragraph::new(format!("Failed to load operation: {error}"))
+                    .style(Color::Red)
+                    .alignment(Alignment::Center)
+                    .wrap(Wrap { trim: false })
+                    .block(block.padding(Padding::vertical(1)));
+                frame.render_widget(paragraph, frame.area());
+                return;
+            }
+        };
+
+        let entry = &detail.entry;
+        let federation = state
+            .federations
+            .iter()
+            .find(|f| f.id == entry.federation_id)
+            .and_then(|f| f.name.clone())
+            .unwrap_or_else(|| entry.federation_id.to_string());
+        let status = match &entry.status {
+            OperationStatus::Pending => Span::from("in flight").style(Color::Yellow),
+            OperationStatus::Complete => Span::from("complete").style(Color::Green),
+            OperationStatus::Refunded => Span::from("refunded").style(Color::Yellow),
+            OperationStatus::Failed(error) => {
+                Span::from(format!("failed: {error}")).style(Color::Red)
+            }
+        };
+
+        let mut lines = vec![
+            Line::from(format!("Kind        {}", entry.kind)),
+            Line::from(format!("Federation  {federation}")),
+            Line::from(vec![
+                Span::from("Amount      "),
+                Span::from(entry.amount.map(format::sats).unwrap_or("-".into()))
+                    .style(Color::Yellow),
+            ]),
+            Line::from(format!(
+                "Fee         {}",
+                entry.fee.map(format::sats).unwrap_or("-".into())
+            )),
+            Line::from(format!("Created     {} UTC", format::timestamp(entry.time))),
+            Line::from(vec![Span::from("Status      "), status]),
+            Line::from(format!(
+                "Label       {}",
+                entry.label.as_deref().unwrap_or("-")
+            )),
+            Line::from(format!("Operation   {}", entry.operation_id.fmt_full())),
+            Line::from(""),
+            Line::from("States").bold(),
+        ];
+
+        for transition in &detail.transitions {
+            lines.push(Line::from(vec![
+                Span::from(format!("{}  ", format::timestamp(transition.time)))
+                    .style(Color::DarkGray),
+                Span::from(transition.state.clone()),
+            ]));
+        }
+
+        if !detail.references.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from("References").bold());
+
+            for (label, value) in &detail.references {
+                lines.push(Line::from(vec![
+                    Span::from(format!("{label}: ")).style(Color::DarkGray),
+                    Span::from(value.clone()),
+                ]));
+            }
+        }
+
+        let area = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        let [label, details] = Layout::vertical([
+            Constraint::Length(if self.label.is_some() { 3 } else { 0 }),
+            Constraint::Min(0),
+        ])
+        .areas(area);
+
+        if let Some(input) = &self.label {
+            input.draw(frame, label, "Label", true);
+        }
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .scroll((self.scroll, 0))
+                .block(Block::new().padding(Padding::uniform(1))),
+            details,
+        );
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::Operation {
+            return Ok(());
+        }
+
+        if let Some(input) = &mut self.label {
+            match event.code {
+                KeyCode::Enter => {
+                    if let OperationView::Loaded(detail) = &state.operation {
+                        tx.send(Message::SetLabel {
+                            operation_id: detail.entry.operation_id,
+                            label: input.value().to_string(),
+                        })
+                        .ok();
+                    }
+                    self.label = None;
+                }
+                KeyCode::Esc => {
+                    self.label = None;
+                }
+                _ => {
+                    input.on_key_event(event);
+                }
+            }
+
+            return Ok(());
+        }
+
+        match event.code {
+            KeyCode::Up => {
+                self.scroll = self.scroll.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                self.scroll = self.scroll.saturating_add(1);
+            }
+            KeyCode::Char('l') => {
+                if let OperationView::Loaded(detail) = &state.operation {
+                    let label = detail.entry.label.clone().unwrap_or_default();
+                    self.label = Some(TextInput::with_value(label));
+                }
+            }
+            KeyCode::Esc => {
+                self.scroll = 0;
+                state.operation = OperationView::Idle;
+                state.navigate(Screen::History);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
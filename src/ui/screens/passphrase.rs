@@ -0,0 +1,191 @@
+use crate::AUTO_LOCK_AFTER;
+use crate::state::{PassphraseChange, Screen};
+use crate::ui::prelude::*;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+    Current,
+    New,
+    Confirm,
+}
+
+/// Sets, changes or removes the passphrase the seed is encrypted with
+pub struct PassphraseScreen {
+    current: TextInput,
+    new: TextInput,
+    confirm: TextInput,
+    focus: Field,
+    error: Option<String>,
+}
+
+impl PassphraseScreen {
+    pub fn new() -> Self {
+        Self {
+            current: TextInput::masked(),
+            new: TextInput::masked(),
+            confirm: TextInput::masked(),
+            focus: Field::Current,
+            error: None,
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// The fields shown, the current passphrase is only asked for once there is one
+    fn fields(encrypted: bool) -> &'static [Field] {
+        match encrypted {
+            true => &[Field::Current, Field::New, Field::Confirm],
+            false => &[Field::New, Field::Confirm],
+        }
+    }
+
+    /// The focused field, falling back to the first one shown
+    fn focus(&self, fields: &[Field]) -> Field {
+        match fields.contains(&self.focus) {
+            true => self.focus,
+            false => fields[0],
+        }
+    }
+}
+
+impl Component for PassphraseScreen {
+    fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        if state.screen != Screen::Passphrase {
+            return;
+        }
+
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(" Passphrase ")
+            .title_bottom(" [tab] next field  [enter] save  [esc] back ")
+            .title_alignment(Alignment::Center);
+        let area = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        let fields = Self::fields(state.encrypted);
+        let focus = self.focus(fields);
+        let mut constraints = vec![Constraint::Length(3); fields.len()];
+        constraints.push(Constraint::Min(0));
+        let areas = Layout::vertical(constraints).split(area);
+
+        for (field, area) in fields.iter().zip(areas.iter()) {
+            let (input, title) = match field {
+                Field::Current => (&self.current, "Current passphrase"),
+                Field::New => (&self.new, "New passphrase"),
+                Field::Confirm => (&self.confirm, "Repeat new passphrase"),
+            };
+
+            input.draw(frame, *area, title, *field == focus);
+        }
+
+        let mut lines = vec![match state.encrypted {
+            true => {
+                Line::from("Your seed is encrypted, leave the new passphrase empty to remove it")
+            }
+            false => Line::from(format!(
+                "Your seed is stored unencrypted, set a passphrase to encrypt it on this device and lock the app after {}m without input",
+                AUTO_LOCK_AFTER.as_secs() / 60
+            )),
+        }];
+        lines.push(
+            Line::from(
+                "The database file may still hold an earlier unencrypted copy of the seed, move your funds to a new profile if the device could be compromised",
+            )
+            .style(Color::Yellow),
+        );
+        lines.push(Line::from(
+            "The passphrase can't be recovered, forgetting it means restoring from your seed words",
+        ));
+        lines.push(Line::from(""));
+
+        lines.push(match &state.passphrase {
+            PassphraseChange::Idle => Line::from(""),
+            PassphraseChange::Saving => Line::from("Saving..."),
+            PassphraseChange::Saved => Line::from("Passphrase saved").style(Color::Green),
+            PassphraseChange::Failed(error) => {
+                Line::from(format!("Failed to save passphrase: {error}")).style(Color::Red)
+            }
+        });
+
+        if let Some(error) = &self.error {
+            lines.push(Line::from(error.as_str()).style(Color::Red));
+        }
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .block(Block::new().padding(Padding::uniform(1))),
+            areas[fields.len()],
+        );
+    }
+
+    fn on_tick(&mut self, state: &AppStateMutex) {
+        // Passphrases typed before the app locked shouldn't wait there for whoever unlocks it
+        if state.lock().unwrap().screen == Screen::Unlock {
+            self.reset();
+        }
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::Passphrase || state.passphrase == PassphraseChange::Saving {
+            return Ok(());
+        }
+
+        let fields = Self::fields(state.encrypted);
+        self.focus = self.focus(fields);
+
+        match event.code {
+            KeyCode::Esc => {
+                self.reset();
+                state.passphrase = PassphraseChange::Idle;
+                state.navigate(Screen::Settings);
+            }
+            KeyCode::Tab | KeyCode::Down | KeyCode::Up => {
+                let position = fields.iter().position(|f| *f == self.focus).unwrap_or(0);
+                let position = match event.code {
+                    KeyCode::Up => (position + fields.len() - 1) % fields.len(),
+                    _ => (position + 1) % fields.len(),
+                };
+                self.focus = fields[position];
+            }
+            KeyCode::Enter => {
+                if self.new.value() != self.confirm.value() {
+                    self.error = Some("The new passphrases don't match".to_string());
+                    return Ok(());
+                }
+
+                tx.send(Message::SetPassphrase {
+                    current: self.current.value().to_string(),
+                    new: self.new.value().to_string(),
+                })
+                .ok();
+                self.reset();
+                state.passphrase = PassphraseChange::Saving;
+            }
+            _ => {
+                let input = match self.focus {
+                    Field::Current => &mut self.current,
+                    Field::New => &mut self.new,
+                    Field::Confirm => &mut self.confirm,
+                };
+
+                input.on_key_event(event);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+use crate::backend::WithdrawAmount;
+use crate::state::{PegOut, Screen};
+use crate::ui::prelude::*;
+use fedimint_core::bitcoin;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+    Address,
+    Amount,
+}
+
+/// Withdraws ecash to an on-chain address
+pub struct PegOutScreen {
+    address: TextInput,
+    amount: TextInput,
+    focus: Field,
+}
+
+impl PegOutScreen {
+    pub fn new() -> Self {
+        Self {
+            address: TextInput::new(),
+            amount: TextInput::new(),
+            focus: Field::Address,
+        }
+    }
+}
+
+impl Component for PegOutScreen {
+    fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        if state.screen != Screen::PegOut {
+            return;
+        }
+
+        let name = state
+            .selected()
+            .map(|f| f.name.clone().unwrap_or_else(|| f.id.to_string()))
+            .unwrap_or_default();
+        let balance = state
+            .selected()
+            .map(|f| format::sats(f.balance))
+            .unwrap_or_default();
+
+        let keys = match state.peg_out {
+            PegOut::Confirm(_) => " [y] withdraw  [n] cancel ",
+            _ => " [tab] switch field  [enter] get fee quote  [esc] back ",
+        };
+
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(format!(" Withdraw on-chain · {name} "))
+            .title(Line::from(format!(" {balance} ")).right_aligned())
+            .title_bottom(keys)
+            .title_alignment(Alignment::Center);
+        let area = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        let [address, amount, status] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Min(0),
+        ])
+        .areas(area);
+
+        let editable = !matches!(state.peg_out, PegOut::Confirm(_));
+        self.address.draw(
+            frame,
+            address,
+            "Bitcoin address",
+            editable && self.focus == Field::Address,
+        );
+        self.amount.draw(
+            frame,
+            amount,
+            "Amount (sats, or \"all\")",
+            editable && self.focus == Field::Amount,
+        );
+
+        let lines = match &state.peg_out {
+            PegOut::Idle => vec![Line::from("Enter an address and amount and press enter")],
+            PegOut::Quoting => vec![Line::from("Getting fee quote from the federation...")],
+            PegOut::Confirm(quote) => {
+                let fee = quote.fees.amount();
+
+                vec![
+                    Line::from(format!("Address  {}", quote.address)),
+                    Line::from(vec![
+                        Span::from("Amount   "),
+                        Span::from(format!("{} sats", quote.amount.to_sat())).style(Color::Yellow),
+                    ]),
+                    Line::from(vec![
+                        Span::from("Fee      "),
+                        Span::from(format!("{} sats", fee.to_sat())).style(Color::Yellow),
+                    ]),
+                    Line::from(format!("Total    {} sats", (quote.amount + fee).to_sat())),
+                    Line::from(""),
+                    Line::from("Withdraw to this address?").bold(),
+                ]
+            }
+            PegOut::Withdrawing => {
+                vec![Line::from(
+                    "Waiting for the federation to sign the transaction...",
+                )]
+            }
+            PegOut::Succeeded { txid } => vec![
+                Line::from("Withdrawal broadcast").style(Color::Green),
+                Line::from(format!("Txid  {txid}")),
+            ],
+            PegOut::Failed(error) => {
+                vec![Line::from(format!("Withdrawal failed: {error}")).style(Color::Red)]
+            }
+        };
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .block(Block::new().padding(Padding::uniform(1))),
+            status,
+        );
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::PegOut {
+            return Ok(());
+        }
+
+        let Some(federation_id) = state.selected_federation else {
+            return Ok(());
+        };
+
+        if let PegOut::Confirm(quote) = &state.peg_out {
+            match event.code {
+                KeyCode::Char('y') => {
+                    tx.send(Message::Withdraw {
+                        federation_id,
+                        quote: quote.clone(),
+                    })
+                    .ok();
+                    state.peg_out = PegOut::Withdrawing;
+                    self.address.clear();
+                    self.amount.clear();
+                    self.focus = Field::Address;
+                }
+                KeyCode::Char('n') | KeyCode::Esc => {
+                    state.peg_out = PegOut::Idle;
+                }
+                _ => {}
+            }
+
+            return Ok(());
+        }
+
+        match event.code {
+            KeyCode::Esc => {
+                state.navigate(Screen::Wallets);
+            }
+            KeyCode::Tab => {
+                self.focus = match self.focus {
+                    Field::Address => Field::Amount,
+                    Field::Amount => Field::Address,
+                };
+            }
+            KeyCode::Enter => {
+                let amount = match self.amount.value().trim() {
+                    all if all.eq_ignore_ascii_case("all") => Some(WithdrawAmount::All),
+                    sats => sats
+                        .parse::<u64>()
+                        .ok()
+                        .map(|sats| WithdrawAmount::Exact(bitcoin::Amount::from_sat(sats))),
+                };
+
+                match amount {
+                    Some(amount) => {
+                        state.peg_out = PegOut::Quoting;
+                        tx.send(Message::QuoteWithdraw {
+                            federation_id,
+                            address: self.address.value().to_string(),
+                            amount,
+                        })
+                        .ok();
+                    }
+                    None => {
+                        state.peg_out =
+                            PegOut::Failed("amount must be a number of sats or \"all\"".into());
+                    }
+                }
+            }
+            _ => match self.focus {
+                Field::Address => {
+                    self.address.on_key_event(event);
+                }
+                Field::Amount => {
+                    self.amount.on_key_event(event);
+                }
+            },
+        }
+
+        Ok(())
+    }
+}
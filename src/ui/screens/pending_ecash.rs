@@ -0,0 +1,152 @@
+use crate::backend::ECASH_SPEND_TIMEOUT;
+use crate::state::{Screen, SpendNotice};
+use crate::ui::prelude::*;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+use std::time::SystemTime;
+
+/// Ecash we handed out that hasn't been redeemed yet, with a way to take it back
+pub struct PendingEcashScreen {
+    selected: usize,
+}
+
+impl PendingEcashScreen {
+    pub fn new() -> Self {
+        Self { selected: 0 }
+    }
+}
+
+impl Component for PendingEcashScreen {
+    fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        if state.screen != Screen::PendingEcash {
+            return;
+        }
+
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(" Unclaimed ecash ")
+            .title_bottom(" [↑↓] select  [c] reclaim  [esc] back ")
+            .title_alignment(Alignment::Center);
+        let area = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        let [table_area, notice_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(area);
+
+        if let Some(notice) = &state.spend_notice {
+            let line = match notice {
+                SpendNotice::Reclaimed(amount) => {
+                    Line::from(format!("Reclaimed {}", format::sats(*amount))).style(Color::Green)
+                }
+                SpendNotice::Redeemed(amount) => {
+                    Line::from(format!("The recipient redeemed {}", format::sats(*amount)))
+                }
+                SpendNotice::Failed(error) => {
+                    Line::from(format!("Failed to reclaim ecash: {error}")).style(Color::Red)
+                }
+            };
+
+            frame.render_widget(
+                Paragraph::new(line).block(Block::new().padding(Padding::horizontal(1))),
+                notice_area,
+            );
+        }
+
+        if state.pending_spends.is_empty() {
+            let paragraph = Paragraph::new("All ecash you sent has been redeemed or reclaimed")
+                .alignment(Alignment::Center)
+                .block(Block::new().padding(Padding::vertical(1)));
+            frame.render_widget(paragraph, table_area);
+            return;
+        }
+
+        let now = SystemTime::now();
+        let rows = state.pending_spends.iter().map(|spend| {
+            let federation = state
+                .federations
+                .iter()
+                .find(|f| f.id == spend.federation_id)
+                .and_then(|f| f.name.clone())
+                .unwrap_or_else(|| spend.federation_id.to_string());
+            let age = now.duration_since(spend.created).unwrap_or_default();
+            let status = if spend.reclaiming {
+                Span::from("reclaiming...").style(Color::Yellow)
+            } else {
+                match ECASH_SPEND_TIMEOUT.checked_sub(age) {
+                    Some(left) => Span::from(format!("reclaims in {}", format::duration(left))),
+                    None => Span::from("timed out, reclaiming...").style(Color::Yellow),
+                }
+            };
+
+            Row::new(vec![
+                Cell::from(federation),
+                Cell::from(format::sats(spend.amount)).style(Color::Yellow),
+                Cell::from(format!("{} ago", format::duration(age))),
+                Cell::from(status),
+            ])
+        });
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Fill(1),
+                Constraint::Length(18),
+                Constraint::Length(10),
+                Constraint::Length(24),
+            ],
+        )
+        .header(Row::new(vec!["Federation", "Amount", "Sent", "Status"]).bold())
+        .row_highlight_style(Style::new().reversed())
+        .block(Block::new().padding(Padding::horizontal(1)));
+
+        let mut table_state = TableState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(table, table_area, &mut table_state);
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::PendingEcash {
+            return Ok(());
+        }
+
+        let count = state.pending_spends.len();
+        self.selected = self.selected.min(count.saturating_sub(1));
+
+        match event.code {
+            KeyCode::Up => {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                self.selected = (self.selected + 1).min(count.saturating_sub(1));
+            }
+            KeyCode::Char('c') => {
+                if let Some(spend) = state.pending_spends.get_mut(self.selected)
+                    && !spend.reclaiming
+                {
+                    spend.reclaiming = true;
+                    tx.send(Message::ReclaimEcash {
+                        federation_id: spend.federation_id,
+                        operation_id: spend.operation_id,
+                    })
+                    .ok();
+                }
+            }
+            KeyCode::Esc => {
+                self.selected = 0;
+                state.spend_notice = None;
+                state.navigate(Screen::Wallets);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+use crate::state::{ProfileAction, Screen};
+use crate::ui::prelude::*;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+/// A profile name being typed in, or a deletion waiting to be confirmed
+enum Edit {
+    Create(TextInput),
+    Rename { from: String, input: TextInput },
+    Delete(String),
+}
+
+/// Picks the profile to open at startup, and creates, renames and deletes profiles from Settings
+pub struct ProfilesScreen {
+    selected: usize,
+    edit: Option<Edit>,
+}
+
+impl ProfilesScreen {
+    pub fn new() -> Self {
+        Self {
+            selected: 0,
+            edit: None,
+        }
+    }
+}
+
+impl Component for ProfilesScreen {
+    fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        if state.screen != Screen::Profiles {
+            return;
+        }
+
+        let keys = match (&self.edit, &state.profile) {
+            (Some(Edit::Delete(_)), _) => " [y] delete  [n] cancel ",
+            (Some(_), _) => " [enter] save  [esc] cancel ",
+            (None, Some(_)) => {
+                " [↑↓] select  [enter] open  [n] new  [r] rename  [d] delete  [esc] back "
+            }
+            (None, None) => " [↑↓] select  [enter] open  [n] new  [r] rename  [d] delete ",
+        };
+
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(" Profiles ")
+            .title_bottom(keys)
+            .title_alignment(Alignment::Center);
+        let area = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        let editing = matches!(self.edit, Some(Edit::Create(_) | Edit::Rename { .. }));
+        let [input, list, status] = Layout::vertical([
+            Constraint::Length(if editing { 3 } else { 0 }),
+            Constraint::Min(0),
+            Constraint::Length(4),
+        ])
+        .areas(area);
+
+        match &self.edit {
+            Some(Edit::Create(name)) => name.draw(frame, input, "New profile", true),
+            Some(Edit::Rename { from, input: name }) => {
+                name.draw(frame, input, &format!("Rename {from}"), true)
+            }
+            _ => {}
+        }
+
+        let items = state.profiles.iter().map(|profile| {
+            if state.profile.as_ref() == Some(profile) {
+                ListItem::new(Line::from(vec![
+                    Span::from(profile.as_str()),
+                    Span::from("  open").style(Color::Green),
+                ]))
+            } else {
+                ListItem::new(profile.as_str())
+            }
+        });
+
+        let mut list_state = ListState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(
+            List::new(items)
+                .highlight_style(Style::new().reversed())
+                .block(Block::new().padding(Padding::horizontal(1))),
+            list,
+            &mut list_state,
+        );
+
+        let line = match (&self.edit, &state.profile_action) {
+            (Some(Edit::Delete(name)), _) => Line::from(format!(
+                "Delete {name}? Its data is moved aside, not erased, so it can be restored by hand"
+            ))
+            .style(Color::Yellow),
+            (_, ProfileAction::Working) => Line::from("Working..."),
+            (_, ProfileAction::Done(done)) => Line::from(done.as_str()).style(Color::Green),
+            (_, ProfileAction::Failed(error)) => Line::from(error.as_str()).style(Color::Red),
+            (_, ProfileAction::Idle) => Line::from("Each profile has its own seed and federations"),
+        };
+
+        frame.render_widget(
+            Paragraph::new(line)
+                .wrap(Wrap { trim: false })
+                .block(Block::new().padding(Padding::uniform(1))),
+            status,
+        );
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::Profiles || state.profile_action == ProfileAction::Working {
+            return Ok(());
+        }
+
+        if let Some(edit) = &mut self.edit {
+            match (edit, event.code) {
+                (Edit::Delete(name), KeyCode::Char('y')) => {
+                    tx.send(Message::DeleteProfile(name.clone())).ok();
+                    state.profile_action = ProfileAction::Working;
+                    self.selected = 0;
+                    self.edit = None;
+                }
+                (Edit::Delete(_), KeyCode::Char('n') | KeyCode::Esc) => {
+                    self.edit = None;
+                }
+                (Edit::Create(name), KeyCode::Enter) => {
+                    tx.send(Message::CreateProfile(name.value().trim().to_string()))
+                        .ok();
+                    state.profile_action = ProfileAction::Working;
+                    self.edit = None;
+                }
+                (Edit::Rename { from, input }, KeyCode::Enter) => {
+                    tx.send(Message::RenameProfile {
+                        from: from.clone(),
+                        to: input.value().trim().to_string(),
+                    })
+                    .ok();
+                    state.profile_action = ProfileAction::Working;
+                    self.edit = None;
+                }
+                (Edit::Create(_) | Edit::Rename { .. }, KeyCode::Esc) => {
+                    self.edit = None;
+                }
+                (Edit::Create(input) | Edit::Rename { input, .. }, _) => {
+                    input.on_key_event(event);
+                }
+                _ => {}
+            }
+
+            return Ok(());
+        }
+
+        let selected = state.profiles.get(self.selected).cloned();
+
+        match event.code {
+            KeyCode::Up => {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                self.selected = (self.selected + 1).min(state.profiles.len().saturating_sub(1));
+            }
+            KeyCode::Enter => {
+                if let Some(profile) = selected {
+                    if state.profile.as_ref() == Some(&profile) {
+                        state.navigate(Screen::Splash);
+                    } else {
+                        tx.send(Message::SelectProfile(profile)).ok();
+                        state.profile_action = ProfileAction::Working;
+                    }
+                }
+            }
+            KeyCode::Char('n') => {
+                state.profile_action = ProfileAction::Idle;
+                self.edit = Some(Edit::Create(TextInput::new()));
+            }
+            KeyCode::Char('r') => {
+                if let Some(profile) = selected {
+                    state.profile_action = ProfileAction::Idle;
+                    self.edit = Some(Edit::Rename {
+                        input: TextInput::with_value(profile.clone()),
+                        from: profile,
+                    });
+                }
+            }
+            KeyCode::Char('d') => {
+                if let Some(profile) = selected {
+                    state.profile_action = ProfileAction::Idle;
+                    self.edit = Some(Edit::Delete(profile));
+                }
+            }
+            KeyCode::Esc if state.profile.is_some() => {
+                state.navigate(Screen::Settings);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+use crate::state::{LightningReceive, Screen};
+use crate::ui::prelude::*;
+use fedimint_core::Amount;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+    Amount,
+    Description,
+}
+
+pub struct ReceiveScreen {
+    amount: TextInput,
+    description: TextInput,
+    focus: Field,
+    /// The invoice's code, encoded once per invoice
+    qr: Option<Qr>,
+}
+
+impl ReceiveScreen {
+    pub fn new() -> Self {
+        Self {
+            amount: TextInput::new(),
+            description: TextInput::new(),
+            focus: Field::Amount,
+            qr: None,
+        }
+    }
+}
+
+impl Component for ReceiveScreen {
+    fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        if state.screen != Screen::Receive {
+            return;
+        }
+
+        let name = state
+            .selected()
+            .map(|f| f.name.clone().unwrap_or_else(|| f.id.to_string()))
+            .unwrap_or_default();
+        let balance = state
+            .selected()
+            .map(|f| format::sats(f.balance))
+            .unwrap_or_default();
+
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(format!(" Receive via Lightning · {name} "))
+            .title(Line::from(format!(" {balance} ")).right_aligned())
+            .title_bottom(" [tab] switch field  [enter] create invoice  [esc] back ")
+            .title_alignment(Alignment::Center);
+        let area = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        let [amount, description, status] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Min(0),
+        ])
+        .areas(area);
+
+        self.amount
+            .draw(frame, amount, "Amount (sats)", self.focus == Field::Amount);
+        self.description.draw(
+            frame,
+            description,
+            "Description",
+            self.focus == Field::Description,
+        );
+
+        let lines = match &state.ln_receive {
+            LightningReceive::Idle => vec![Line::from("Enter an amount and press enter")],
+            LightningReceive::CreatingInvoice => vec![Line::from("Creating invoice...")],
+            LightningReceive::AwaitingPayment { invoice } => {
+                let [message, code] =
+                    Layout::vertical([Constraint::Length(2), Constraint::Min(0)]).areas(status);
+
+                frame.render_widget(
+                    Paragraph::new("Waiting for payment")
+                        .style(Color::Yellow)
+                        .block(Block::new().padding(Padding::horizontal(1))),
+                    message,
+                );
+
+                match &self.qr {
+                    Some(qr) => qr.draw_with_caption(frame, code, invoice),
+                    None => frame.render_widget(Paragraph::new(invoice.as_str()), code),
+                }
+
+                return;
+            }
+            LightningReceive::Funded { .. } => {
+                vec![Line::from("Payment received, claiming ecash...").style(Color::Yellow)]
+            }
+            LightningReceive::Claimed { amount } => {
+                vec![Line::from(format!("Received {}", format::sats(*amount))).style(Color::Green)]
+            }
+            LightningReceive::Canceled(reason) => {
+                vec![Line::from(format!("Invoice canceled: {reason}")).style(Color::Red)]
+            }
+            LightningReceive::Failed(error) => {
+                vec![Line::from(format!("Failed to create invoice: {error}")).style(Color::Red)]
+            }
+        };
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .block(Block::new().padding(Padding::uniform(1))),
+            status,
+        );
+    }
+
+    fn on_tick(&mut self, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        // Invoices are case insensitive, and uppercase encodes to a smaller code
+        let data = match &state.ln_receive {
+            LightningReceive::AwaitingPayment { invoice } => Some(invoice.to_uppercase()),
+            _ => None,
+        };
+
+        Qr::cache(&mut self.qr, data.as_deref());
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::Receive {
+            return Ok(());
+        }
+
+        match event.code {
+            KeyCode::Esc => {
+                state.navigate(Screen::Wallets);
+            }
+            KeyCode::Tab => {
+                self.focus = match self.focus {
+                    Field::Amount => Field::Description,
+                    Field::Description => Field::Amount,
+                };
+            }
+            KeyCode::Enter => {
+                let Some(federation_id) = state.selected_federation else {
+                    return Ok(());
+                };
+
+                if state.ln_receive == LightningReceive::CreatingInvoice {
+                    return Ok(());
+                }
+
+                match self.amount.value().trim().parse::<u64>() {
+                    Ok(sats) if sats > 0 => {
+                        state.ln_receive = LightningReceive::CreatingInvoice;
+                        tx.send(Message::ReceiveLightning {
+                            federation_id,
+                            amount: Amount::from_sats(sats),
+                            description: self.description.value().to_string(),
+                        })
+                        .ok();
+                    }
+                    _ => {
+                        state.ln_receive = LightningReceive::Failed(
+                            "amount must be a whole number of sats".into(),
+                        );
+                    }
+                }
+            }
+            _ => match self.focus {
+                Field::Amount => {
+                    self.amount.on_key_event(event);
+                }
+                Field::Description => {
+                    self.description.on_key_event(event);
+                }
+            },
+        }
+
+        Ok(())
+    }
+}
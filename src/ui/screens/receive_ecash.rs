@@ -0,0 +1,212 @@
+use crate::state::{EcashReceive, Screen};
+use crate::ui::prelude::*;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+/// Checks and redeems ecash notes pasted as text or as the frames of an animated QR code
+pub struct ReceiveEcashScreen {
+    notes: TextInput,
+    frames: FrameAssembler,
+    error: Option<String>,
+}
+
+impl ReceiveEcashScreen {
+    pub fn new() -> Self {
+        Self {
+            notes: TextInput::new(),
+            frames: FrameAssembler::default(),
+            error: None,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.notes.clear();
+        self.frames = FrameAssembler::default();
+        self.error = None;
+    }
+}
+
+impl Component for ReceiveEcashScreen {
+    fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        if state.screen != Screen::ReceiveEcash {
+            return;
+        }
+
+        let keys = match state.ecash_receive {
+            EcashReceive::Confirm(ref preview) if preview.joined => " [y] redeem  [n] cancel ",
+            EcashReceive::Confirm(_) => " [n] cancel ",
+            _ => " [enter] check notes  [esc] back ",
+        };
+
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(" Receive ecash ")
+            .title_bottom(keys)
+            .title_alignment(Alignment::Center);
+        let area = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        let [notes, status] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+        let editable = !matches!(state.ecash_receive, EcashReceive::Confirm(_));
+        self.notes.draw(frame, notes, "Notes or QR frame", editable);
+
+        let mut lines = match &state.ecash_receive {
+            EcashReceive::Idle => vec![Line::from(
+                "Paste ecash notes, or each frame of an animated QR code, and press enter",
+            )],
+            EcashReceive::Parsing => vec![Line::from("Checking notes...")],
+            EcashReceive::Confirm(preview) => {
+                let federation = match (&preview.federation_name, preview.federation_id) {
+                    (Some(name), _) => name.clone(),
+                    (None, Some(id)) => id.to_string(),
+                    (None, None) => format!("unknown ({}...)", preview.federation_prefix),
+                };
+
+                let mut lines = vec![
+                    Line::from(vec![
+                        Span::from("Amount      "),
+                        Span::from(format::sats(preview.amount)).style(Color::Yellow),
+                    ]),
+                    Line::from(format!("Federation  {federation}")),
+                ];
+
+                if preview.joined {
+                    lines.push(Line::from("Joined      yes").style(Color::Green));
+                    lines.push(Line::from(""));
+                    lines.push(Line::from("Redeem these notes?").bold());
+                } else {
+                    lines.push(Line::from("Joined      no").style(Color::Red));
+                    lines.push(Line::from(""));
+                    lines.push(Line::from(
+                        "Join this federation before redeeming notes from it",
+                    ));
+
+                    if let Some(invite_code) = &preview.invite_code {
+                        lines.push(Line::from(format!("Invite code {invite_code}")));
+                    }
+                }
+
+                lines
+            }
+            EcashReceive::Reissuing { amount } => vec![
+                Line::from(format!("Redeeming {}...", format::sats(*amount))).style(Color::Yellow),
+                Line::from("Submitted to the federation"),
+            ],
+            EcashReceive::Issuing { amount } => vec![
+                Line::from(format!("Redeeming {}...", format::sats(*amount))).style(Color::Yellow),
+                Line::from("Federation is issuing fresh notes"),
+            ],
+            EcashReceive::Received { amount } => {
+                vec![Line::from(format!("Received {}", format::sats(*amount))).style(Color::Green)]
+            }
+            EcashReceive::Failed(error) => {
+                vec![Line::from(format!("Failed to redeem ecash: {error}")).style(Color::Red)]
+            }
+        };
+
+        if self.frames.frames() > 0 {
+            let needed = self
+                .frames
+                .needed()
+                .map(|n| format!(" (needs at least {n})"))
+                .unwrap_or_default();
+
+            lines.push(Line::from(""));
+            lines.push(Line::from(format!(
+                "{} frames scanned{needed}",
+                self.frames.frames()
+            )));
+        }
+
+        if let Some(error) = &self.error {
+            lines.push(Line::from(""));
+            lines.push(Line::from(error.as_str()).style(Color::Red));
+        }
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .block(Block::new().padding(Padding::uniform(1))),
+            status,
+        );
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::ReceiveEcash {
+            return Ok(());
+        }
+
+        if let EcashReceive::Confirm(preview) = &state.ecash_receive {
+            match event.code {
+                KeyCode::Char('y') if preview.joined => {
+                    if let Some(federation_id) = preview.federation_id {
+                        tx.send(Message::ReceiveEcash {
+                            federation_id,
+                            notes: preview.notes.clone(),
+                        })
+                        .ok();
+                        state.ecash_receive = EcashReceive::Reissuing {
+                            amount: preview.amount,
+                        };
+                    }
+                }
+                KeyCode::Char('n') | KeyCode::Esc => {
+                    state.ecash_receive = EcashReceive::Idle;
+                }
+                _ => {}
+            }
+
+            return Ok(());
+        }
+
+        match event.code {
+            KeyCode::Esc => {
+                self.reset();
+                state.ecash_receive = EcashReceive::Idle;
+                state.navigate(Screen::Wallets);
+            }
+            KeyCode::Enter if !self.notes.value().trim().is_empty() => {
+                let input = self.notes.value().trim().to_string();
+                self.notes.clear();
+                self.error = None;
+
+                let notes = if FrameAssembler::is_frame(&input) {
+                    match self
+                        .frames
+                        .receive(&input)
+                        .and_then(|_| self.frames.message())
+                    {
+                        Ok(Some(notes)) => notes,
+                        Ok(None) => return Ok(()),
+                        Err(e) => {
+                            self.error = Some(e.to_string());
+                            return Ok(());
+                        }
+                    }
+                } else {
+                    input
+                };
+
+                self.frames = FrameAssembler::default();
+                state.ecash_receive = EcashReceive::Parsing;
+                tx.send(Message::PreviewEcash(notes)).ok();
+            }
+            _ => {
+                self.notes.on_key_event(event);
+            }
+        }
+
+        Ok(())
+    }
+}
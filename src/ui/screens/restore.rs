@@ -0,0 +1,247 @@
+use crate::state::{RecoveryStatus, Restore, Screen};
+use crate::ui::prelude::*;
+use fedimint_core::invite_code::InviteCode;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+use std::str::FromStr;
+
+/// Restores an existing seed, then recovers the federations it was used with one by one
+pub struct RestoreScreen {
+    words: TextInput,
+    invite_code: TextInput,
+    /// Asked for when confirming, an encrypted seed's passphrase is kept for the restored one
+    passphrase: TextInput,
+    error: Option<String>,
+    /// The words were entered and the user is asked to confirm replacing the current seed
+    confirming: bool,
+}
+
+impl RestoreScreen {
+    pub fn new() -> Self {
+        Self {
+            words: TextInput::new(),
+            invite_code: TextInput::new(),
+            passphrase: TextInput::masked(),
+            error: None,
+            confirming: false,
+        }
+    }
+}
+
+impl Component for RestoreScreen {
+    fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        if state.screen != Screen::Restore {
+            return;
+        }
+
+        let keys = match state.restore {
+            Restore::Restored => " [enter] recover federation  [esc] wallets ",
+            Restore::Restoring => "",
+            _ if self.confirming && state.encrypted => " [enter] replace seed  [esc] cancel ",
+            _ if self.confirming => " [y] replace seed  [n] cancel ",
+            _ => " [enter] restore seed  [esc] back ",
+        };
+
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(" Restore from seed ")
+            .title_bottom(keys)
+            .title_alignment(Alignment::Center);
+        let area = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        let [input, status] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+        let mut lines = match &state.restore {
+            Restore::Restored => {
+                self.invite_code.draw(frame, input, "Invite code", true);
+
+                vec![
+                    Line::from("Seed restored").style(Color::Green),
+                    Line::from(
+                        "Enter the invite code of each federation you used this seed with, their ecash is rebuilt from the federation",
+                    ),
+                ]
+            }
+            restore => {
+                if self.confirming && state.encrypted {
+                    self.passphrase.draw(frame, input, "Passphrase", true);
+                } else {
+                    let editable = !matches!(restore, Restore::Restoring) && !self.confirming;
+                    self.words.draw(frame, input, "Seed words", editable);
+                }
+
+                match restore {
+                    _ if self.confirming => {
+                        let mut lines = vec![
+                            Line::from("This replaces the wallet's current seed")
+                                .style(Color::Yellow),
+                            Line::from(
+                                "Anything the current seed holds can only be restored with its own words, write them down first",
+                            ),
+                        ];
+
+                        if state.encrypted {
+                            lines.push(Line::from(
+                                "Enter the wallet's passphrase, the restored seed is encrypted with it",
+                            ));
+                        }
+                        lines
+                    }
+                    Restore::Restoring => vec![Line::from("Restoring seed...")],
+                    Restore::Failed(error) => {
+                        vec![
+                            Line::from(format!("Failed to restore seed: {error}"))
+                                .style(Color::Red),
+                        ]
+                    }
+                    _ => vec![Line::from(
+                        "Enter the 12 or 24 words of your seed, separated by spaces, and press enter",
+                    )],
+                }
+            }
+        };
+
+        if !state.recoveries.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from("Recovery").bold());
+        }
+
+        for recovery in &state.recoveries {
+            let name = recovery
+                .name
+                .clone()
+                .unwrap_or(recovery.federation_id.to_string());
+
+            let status = match (&recovery.status, recovery.status.percent()) {
+                (RecoveryStatus::Joining, _) => Span::from("joining...").style(Color::Yellow),
+                (RecoveryStatus::Recovering { .. }, Some(percent)) => {
+                    Span::from(format!("recovering {percent}%")).style(Color::Yellow)
+                }
+                (RecoveryStatus::Recovering { .. }, None) => {
+                    Span::from("recovering...").style(Color::Yellow)
+                }
+                (RecoveryStatus::Done, _) => Span::from("recovered").style(Color::Green),
+                (RecoveryStatus::Failed(error), _) => {
+                    Span::from(format!("failed: {error}")).style(Color::Red)
+                }
+            };
+
+            lines.push(Line::from(vec![Span::from(format!("{name}  ")), status]));
+        }
+
+        if let Some(error) = &self.error {
+            lines.push(Line::from(""));
+            lines.push(Line::from(error.as_str()).style(Color::Red));
+        }
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .block(Block::new().padding(Padding::uniform(1))),
+            status,
+        );
+    }
+
+    fn on_tick(&mut self, state: &AppStateMutex) {
+        // Seed words typed before the app locked shouldn't wait there for whoever unlocks it
+        if state.lock().unwrap().screen == Screen::Unlock {
+            self.words.clear();
+            self.passphrase.clear();
+            self.confirming = false;
+        }
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::Restore {
+            return Ok(());
+        }
+
+        if self.confirming {
+            match (state.encrypted, event.code) {
+                (true, KeyCode::Enter) if !self.passphrase.value().is_empty() => {
+                    state.restore = Restore::Restoring;
+                    tx.send(Message::RestoreSeed {
+                        words: self.words.value().to_string(),
+                        passphrase: self.passphrase.value().to_string(),
+                    })
+                    .ok();
+                    self.words.clear();
+                    self.passphrase.clear();
+                    self.confirming = false;
+                }
+                (true, KeyCode::Esc) => {
+                    self.passphrase.clear();
+                    self.confirming = false;
+                }
+                (true, _) => {
+                    self.passphrase.on_key_event(event);
+                }
+                (false, KeyCode::Char('y')) => {
+                    state.restore = Restore::Restoring;
+                    tx.send(Message::RestoreSeed {
+                        words: self.words.value().to_string(),
+                        passphrase: String::new(),
+                    })
+                    .ok();
+                    self.words.clear();
+                    self.confirming = false;
+                }
+                (false, KeyCode::Char('n') | KeyCode::Esc) => {
+                    self.confirming = false;
+                }
+                _ => {}
+            }
+
+            return Ok(());
+        }
+
+        match (&state.restore, event.code) {
+            (Restore::Restoring, _) => {}
+            (Restore::Restored, KeyCode::Esc) => {
+                self.invite_code.clear();
+                self.error = None;
+                state.navigate(Screen::Wallets);
+            }
+            (Restore::Restored, KeyCode::Enter) => {
+                let invite_code = self.invite_code.value().trim().to_string();
+
+                match InviteCode::from_str(&invite_code) {
+                    Ok(_) => {
+                        self.invite_code.clear();
+                        self.error = None;
+                        tx.send(Message::RecoverFederation(invite_code)).ok();
+                    }
+                    Err(e) => self.error = Some(format!("Invalid invite code: {e}")),
+                }
+            }
+            (Restore::Restored, _) => {
+                self.invite_code.on_key_event(event);
+            }
+            (_, KeyCode::Esc) => {
+                // Don't keep the seed around once the user backs out
+                self.words.clear();
+                state.restore = Restore::Idle;
+                state.navigate(Screen::Splash);
+            }
+            (_, KeyCode::Enter) if !self.words.value().trim().is_empty() => {
+                self.confirming = true;
+            }
+            _ => {
+                self.words.on_key_event(event);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,231 @@
+use crate::state::{Screen, SeedBackup, SeedReveal};
+use crate::ui::prelude::*;
+use rand::{seq::index, thread_rng};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+/// How many words the user has to repeat before the backup counts as done
+const QUIZ_WORDS: usize = 3;
+
+/// Words the user is asked to repeat, as positions in the seed
+struct Quiz {
+    positions: Vec<usize>,
+    current: usize,
+}
+
+/// Shows the seed words on request, then checks the user wrote them down
+pub struct SeedScreen {
+    quiz: Option<Quiz>,
+    answer: TextInput,
+    error: Option<String>,
+}
+
+impl SeedScreen {
+    pub fn new() -> Self {
+        Self {
+            quiz: None,
+            answer: TextInput::new(),
+            error: None,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.quiz = None;
+        self.answer.clear();
+        self.error = None;
+    }
+
+    fn word_lines(words: &[String]) -> Vec<Line<'static>> {
+        let rows = words.len().div_ceil(3);
+
+        (0..rows)
+            .map(|row| {
+                Line::from(
+                    (0..3)
+                        .filter_map(|column| {
+                            let i = column * rows + row;
+                            let word = words.get(i)?;
+
+                            Some(Span::from(format!("{:>2}. {word:<12}", i + 1)))
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl Component for SeedScreen {
+    fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        if state.screen != Screen::Seed {
+            return;
+        }
+
+        let keys = match (&state.seed, &self.quiz) {
+            (SeedReveal::Revealed(_), Some(_)) => " [enter] check  [esc] show words again ",
+            (SeedReveal::Revealed(_), None) => " [enter] I wrote them down  [esc] back ",
+            _ => " [r] reveal words  [esc] back ",
+        };
+
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(" Seed backup ")
+            .title_bottom(keys)
+            .title_alignment(Alignment::Center);
+        let area = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        let mut lines = match (&state.seed, &self.quiz) {
+            (SeedReveal::Revealed(_), Some(quiz)) => {
+                let [input, status] =
+                    Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+                let position = quiz.positions[quiz.current] + 1;
+                self.answer
+                    .draw(frame, input, &format!("Word #{position}"), true);
+
+                frame.render_widget(
+                    Paragraph::new(vec![
+                        Line::from(format!(
+                            "Enter word #{position} from your written copy ({} of {})",
+                            quiz.current + 1,
+                            quiz.positions.len()
+                        )),
+                        Line::from(""),
+                        Line::from(self.error.clone().unwrap_or_default()).style(Color::Red),
+                    ])
+                    .wrap(Wrap { trim: false })
+                    .block(Block::new().padding(Padding::uniform(1))),
+                    status,
+                );
+                return;
+            }
+            (SeedReveal::Revealed(words), None) => {
+                let mut lines = vec![
+                    Line::from("Write these words down in order and keep them somewhere safe")
+                        .bold(),
+                    Line::from("Anyone with them can spend your ecash").style(Color::Red),
+                    Line::from(""),
+                ];
+                lines.extend(Self::word_lines(words));
+                lines
+            }
+            (SeedReveal::Loading, _) => vec![Line::from("Loading seed...")],
+            (SeedReveal::Failed(error), _) => {
+                vec![Line::from(format!("Failed to load seed: {error}")).style(Color::Red)]
+            }
+            (SeedReveal::Hidden, _) => vec![
+                Line::from(
+                    "Your seed is the only way to recover your wallet if this device is lost",
+                ),
+                Line::from(""),
+                Line::from("Make sure nobody can see your screen before revealing it"),
+            ],
+        };
+
+        if state.seed_backup == SeedBackup::BackedUp && state.seed == SeedReveal::Hidden {
+            lines.push(Line::from(""));
+            lines.push(Line::from("Seed backup confirmed").style(Color::Green));
+        }
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .block(Block::new().padding(Padding::uniform(1))),
+            area,
+        );
+    }
+
+    fn on_tick(&mut self, state: &AppStateMutex) {
+        // Quiz answers are seed words, they shouldn't wait there for whoever unlocks the app
+        if state.lock().unwrap().screen == Screen::Unlock {
+            self.reset();
+        }
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::Seed {
+            return Ok(());
+        }
+
+        let SeedReveal::Revealed(words) = &state.seed else {
+            match event.code {
+                KeyCode::Char('r') if state.seed != SeedReveal::Loading => {
+                    state.seed = SeedReveal::Loading;
+                    tx.send(Message::RevealSeed).ok();
+                }
+                KeyCode::Esc => {
+                    self.reset();
+                    state.seed = SeedReveal::Hidden;
+                    state.navigate(Screen::Wallets);
+                }
+                _ => {}
+            }
+
+            return Ok(());
+        };
+
+        let Some(quiz) = &mut self.quiz else {
+            match event.code {
+                KeyCode::Enter => {
+                    let mut positions =
+                        index::sample(&mut thread_rng(), words.len(), QUIZ_WORDS).into_vec();
+                    positions.sort();
+
+                    self.quiz = Some(Quiz {
+                        positions,
+                        current: 0,
+                    });
+                }
+                KeyCode::Esc => {
+                    // Don't keep the words in memory longer than needed
+                    self.reset();
+                    state.seed = SeedReveal::Hidden;
+                    state.navigate(Screen::Wallets);
+                }
+                _ => {}
+            }
+
+            return Ok(());
+        };
+
+        match event.code {
+            KeyCode::Enter => {
+                let position = quiz.positions[quiz.current];
+                let answer = self.answer.value().trim().to_lowercase();
+                self.answer.clear();
+
+                if words[position] != answer {
+                    self.error = Some(format!(
+                        "That isn't word #{}, check your written copy",
+                        position + 1
+                    ));
+                    return Ok(());
+                }
+
+                self.error = None;
+                quiz.current += 1;
+
+                if quiz.current == quiz.positions.len() {
+                    self.quiz = None;
+                    tx.send(Message::ConfirmSeedBackup).ok();
+                }
+            }
+            KeyCode::Esc => self.reset(),
+            _ => {
+                self.answer.on_key_event(event);
+            }
+        }
+
+        Ok(())
+    }
+}
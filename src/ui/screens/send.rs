@@ -0,0 +1,262 @@
+use crate::backend::PayRequest;
+use crate::state::{LightningSend, Screen};
+use crate::ui::prelude::*;
+use fedimint_core::Amount;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+    Amount,
+    Comment,
+}
+
+pub struct SendScreen {
+    invoice: TextInput,
+    amount: TextInput,
+    comment: TextInput,
+    focus: Field,
+}
+
+impl SendScreen {
+    pub fn new() -> Self {
+        Self {
+            invoice: TextInput::new(),
+            amount: TextInput::new(),
+            comment: TextInput::new(),
+            focus: Field::Amount,
+        }
+    }
+
+    /// Draws the amount and comment form for an LNURL-pay request
+    fn draw_pay_request(&self, frame: &mut Frame, area: Rect, request: &PayRequest) {
+        let [info, amount, comment] = Layout::vertical([
+            Constraint::Length(4),
+            Constraint::Length(3),
+            Constraint::Length(3),
+        ])
+        .areas(area);
+
+        let lines = vec![
+            Line::from(format!("Paying       {}", request.target)),
+            Line::from(format!("Description  {}", request.description)),
+            Line::from(format!(
+                "Amount       {} to {}",
+                format::sats(request.min_sendable),
+                format::sats(request.max_sendable)
+            )),
+        ];
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::new().padding(Padding::horizontal(1))),
+            info,
+        );
+
+        self.amount
+            .draw(frame, amount, "Amount (sats)", self.focus == Field::Amount);
+
+        if request.comment_allowed > 0 {
+            let title = format!("Comment (up to {} characters)", request.comment_allowed);
+            self.comment
+                .draw(frame, comment, &title, self.focus == Field::Comment);
+        }
+    }
+}
+
+impl Component for SendScreen {
+    fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        if state.screen != Screen::Send {
+            return;
+        }
+
+        let name = state
+            .selected()
+            .map(|f| f.name.clone().unwrap_or_else(|| f.id.to_string()))
+            .unwrap_or_default();
+        let balance = state
+            .selected()
+            .map(|f| format::sats(f.balance))
+            .unwrap_or_default();
+
+        let keys = match state.ln_send {
+            LightningSend::Confirm(_) => " [y] pay  [n] cancel ",
+            LightningSend::EnterAmount(_) => " [tab] switch field  [enter] continue  [esc] cancel ",
+            _ => " [enter] review payment  [esc] back ",
+        };
+
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(format!(" Send via Lightning · {name} "))
+            .title(Line::from(format!(" {balance} ")).right_aligned())
+            .title_bottom(keys)
+            .title_alignment(Alignment::Center);
+        let area = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        let [invoice, status] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+        let editable = !matches!(
+            state.ln_send,
+            LightningSend::Confirm(_) | LightningSend::EnterAmount(_)
+        );
+        self.invoice.draw(
+            frame,
+            invoice,
+            "Invoice, LNURL or lightning address",
+            editable,
+        );
+
+        let lines = match &state.ln_send {
+            LightningSend::Idle => vec![Line::from(
+                "Paste an invoice, LNURL or lightning address and press enter",
+            )],
+            LightningSend::EnterAmount(request) => {
+                self.draw_pay_request(frame, status, request);
+                return;
+            }
+            LightningSend::Decoding => vec![Line::from("Checking invoice...")],
+            LightningSend::Confirm(quote) => vec![
+                Line::from(vec![
+                    Span::from("Amount       "),
+                    Span::from(format::sats(quote.amount)).style(Color::Yellow),
+                ]),
+                Line::from(vec![
+                    Span::from("Gateway fee  "),
+                    Span::from(format::sats(quote.fee)).style(Color::Yellow),
+                ]),
+                Line::from(format!("Payee        {}", quote.payee)),
+                Line::from(format!("Description  {}", quote.description)),
+                Line::from(""),
+                Line::from("Pay this invoice?").bold(),
+            ],
+            LightningSend::Paying { .. } => vec![Line::from("Sending payment...")],
+            LightningSend::Funded { .. } => {
+                vec![Line::from("Payment funded, waiting for the gateway...").style(Color::Yellow)]
+            }
+            LightningSend::Refunding { reason, .. } => vec![
+                Line::from(format!("Payment failed: {reason}")).style(Color::Red),
+                Line::from("Waiting for the refund..."),
+            ],
+            LightningSend::Succeeded { amount, fee } => vec![
+                Line::from(format!(
+                    "Paid {} (fee {})",
+                    format::sats(*amount),
+                    format::sats(*fee)
+                ))
+                .style(Color::Green),
+            ],
+            LightningSend::Refunded(reason) => {
+                vec![Line::from(format!("Payment refunded: {reason}")).style(Color::Yellow)]
+            }
+            LightningSend::Failed(error) => {
+                vec![Line::from(format!("Payment failed: {error}")).style(Color::Red)]
+            }
+        };
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .block(Block::new().padding(Padding::uniform(1))),
+            status,
+        );
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::Send {
+            return Ok(());
+        }
+
+        let Some(federation_id) = state.selected_federation else {
+            return Ok(());
+        };
+
+        if let LightningSend::EnterAmount(request) = &state.ln_send {
+            match event.code {
+                KeyCode::Tab if request.comment_allowed > 0 => {
+                    self.focus = match self.focus {
+                        Field::Amount => Field::Comment,
+                        Field::Comment => Field::Amount,
+                    };
+                }
+                KeyCode::Enter => {
+                    // The service checks the amount against its limits, we only need a number
+                    if let Ok(sats) = self.amount.value().trim().parse::<u64>() {
+                        tx.send(Message::RequestLnurlInvoice {
+                            federation_id,
+                            request: request.clone(),
+                            amount: Amount::from_sats(sats),
+                            comment: self.comment.value().to_string(),
+                        })
+                        .ok();
+                        state.ln_send = LightningSend::Decoding;
+                        self.amount.clear();
+                        self.comment.clear();
+                        self.focus = Field::Amount;
+                    }
+                }
+                KeyCode::Esc => {
+                    state.ln_send = LightningSend::Idle;
+                }
+                _ => match self.focus {
+                    Field::Amount => {
+                        self.amount.on_key_event(event);
+                    }
+                    Field::Comment => {
+                        self.comment.on_key_event(event);
+                    }
+                },
+            }
+
+            return Ok(());
+        }
+
+        if let LightningSend::Confirm(quote) = &state.ln_send {
+            match event.code {
+                KeyCode::Char('y') => {
+                    let invoice = quote.invoice.to_string();
+                    tx.send(Message::PayInvoice {
+                        federation_id,
+                        quote: quote.clone(),
+                    })
+                    .ok();
+                    state.ln_send = LightningSend::Paying { invoice };
+                    self.invoice.clear();
+                }
+                KeyCode::Char('n') | KeyCode::Esc => {
+                    state.ln_send = LightningSend::Idle;
+                }
+                _ => {}
+            }
+
+            return Ok(());
+        }
+
+        match event.code {
+            KeyCode::Esc => {
+                state.navigate(Screen::Wallets);
+            }
+            KeyCode::Enter if !self.invoice.value().is_empty() => {
+                state.ln_send = LightningSend::Decoding;
+                tx.send(Message::QuoteInvoice {
+                    federation_id,
+                    invoice: self.invoice.value().to_string(),
+                })
+                .ok();
+            }
+            _ => {
+                self.invoice.on_key_event(event);
+            }
+        }
+
+        Ok(())
+    }
+}
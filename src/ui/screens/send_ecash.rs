@@ -0,0 +1,193 @@
+use crate::state::{EcashSend, Screen};
+use crate::ui::prelude::*;
+use fedimint_core::Amount;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+/// Notes longer than this are shown as an animated code, a single one gets too dense to scan
+const SINGLE_QR_LIMIT: usize = 500;
+
+enum NotesCode {
+    Single { notes: String, qr: Qr },
+    Animated(AnimatedQr),
+}
+
+impl NotesCode {
+    fn new(notes: &str) -> anyhow::Result<Self> {
+        Ok(if notes.len() <= SINGLE_QR_LIMIT {
+            NotesCode::Single {
+                notes: notes.to_string(),
+                qr: Qr::new(notes)?,
+            }
+        } else {
+            NotesCode::Animated(AnimatedQr::new(notes)?)
+        })
+    }
+
+    fn notes(&self) -> &str {
+        match self {
+            NotesCode::Single { notes, .. } => notes,
+            NotesCode::Animated(animated) => animated.data(),
+        }
+    }
+}
+
+/// Spends ecash notes to hand to someone else
+pub struct SendEcashScreen {
+    amount: TextInput,
+    code: Option<NotesCode>,
+}
+
+impl SendEcashScreen {
+    pub fn new() -> Self {
+        Self {
+            amount: TextInput::new(),
+            code: None,
+        }
+    }
+}
+
+impl Component for SendEcashScreen {
+    fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        if state.screen != Screen::SendEcash {
+            return;
+        }
+
+        let name = state
+            .selected()
+            .map(|f| f.name.clone().unwrap_or_else(|| f.id.to_string()))
+            .unwrap_or_default();
+        let balance = state
+            .selected()
+            .map(|f| format::sats(f.balance))
+            .unwrap_or_default();
+
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(format!(" Send ecash · {name} "))
+            .title(Line::from(format!(" {balance} ")).right_aligned())
+            .title_bottom(" [enter] create notes  [esc] back ")
+            .title_alignment(Alignment::Center);
+        let area = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        let [amount, status] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+        self.amount.draw(frame, amount, "Amount (sats)", true);
+
+        let lines = match &state.ecash_send {
+            EcashSend::Idle => vec![Line::from("Enter an amount and press enter")],
+            EcashSend::Spending => vec![Line::from("Selecting notes...")],
+            EcashSend::Spent { amount, notes } => {
+                let [message, code] =
+                    Layout::vertical([Constraint::Length(2), Constraint::Min(0)]).areas(status);
+
+                let hint = match self.code {
+                    Some(NotesCode::Animated(_)) => " · keep scanning until your wallet finishes",
+                    _ => "",
+                };
+                frame.render_widget(
+                    Paragraph::new(format!("Notes for {}{hint}", format::sats(*amount)))
+                        .style(Color::Green)
+                        .block(Block::new().padding(Padding::horizontal(1))),
+                    message,
+                );
+
+                match &self.code {
+                    Some(NotesCode::Single { qr, .. }) => qr.draw_with_caption(frame, code, notes),
+                    Some(NotesCode::Animated(animated)) => {
+                        let width = code.width.max(1) as usize;
+                        let height = notes.chars().count().div_ceil(width) as u16;
+                        let [qr, text] =
+                            Layout::vertical([Constraint::Min(0), Constraint::Length(height)])
+                                .areas(code);
+
+                        frame.render_widget(animated, qr);
+                        frame.render_widget(
+                            Paragraph::new(notes.as_str())
+                                .style(Color::DarkGray)
+                                .wrap(Wrap { trim: false }),
+                            text,
+                        );
+                    }
+                    None => frame.render_widget(
+                        Paragraph::new(notes.as_str()).wrap(Wrap { trim: false }),
+                        code,
+                    ),
+                }
+
+                return;
+            }
+            EcashSend::Failed(error) => {
+                vec![Line::from(format!("Failed to spend ecash: {error}")).style(Color::Red)]
+            }
+        };
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .block(Block::new().padding(Padding::uniform(1))),
+            status,
+        );
+    }
+
+    fn on_tick(&mut self, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        let EcashSend::Spent { notes, .. } = &state.ecash_send else {
+            self.code = None;
+            return;
+        };
+
+        if self.code.as_ref().map(NotesCode::notes) != Some(notes.as_str()) {
+            self.code = NotesCode::new(notes).ok();
+        }
+
+        if let Some(NotesCode::Animated(animated)) = &mut self.code {
+            animated.tick().ok();
+        }
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::SendEcash {
+            return Ok(());
+        }
+
+        match event.code {
+            KeyCode::Esc => {
+                state.ecash_send = EcashSend::Idle;
+                state.navigate(Screen::Wallets);
+            }
+            KeyCode::Enter => {
+                let Some(federation_id) = state.selected_federation else {
+                    return Ok(());
+                };
+
+                if let Ok(sats) = self.amount.value().trim().parse::<u64>() {
+                    state.ecash_send = EcashSend::Spending;
+                    tx.send(Message::SpendEcash {
+                        federation_id,
+                        amount: Amount::from_sats(sats),
+                    })
+                    .ok();
+                    self.amount.clear();
+                }
+            }
+            _ => {
+                self.amount.on_key_event(event);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
-use crate::state::Screen;
+use crate::AUTO_LOCK_AFTER;
+use crate::state::{PassphraseChange, ProfileAction, Screen, SeedBackup, SeedReveal};
 use crate::ui::prelude::*;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
@@ -13,8 +14,99 @@ impl Component for SettingsScreen {
             return;
         }
 
-        let paragraph = ratatui::widgets::Paragraph::new("Settings");
-        let block = Block::bordered().border_style(Style::new().green().on_white().bold().italic());
-        frame.render_widget(paragraph.clone().block(block), frame.area());
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(" Settings ")
+            .title_bottom(" [esc] back ")
+            .title_alignment(Alignment::Center);
+
+        let passphrase = match state.encrypted {
+            true => Span::from("encrypted with a passphrase").style(Color::Green),
+            false => Span::from("not encrypted").style(Color::Yellow),
+        };
+        let auto_lock = match state.encrypted {
+            true => Span::from(format!(
+                "after {}m without input",
+                AUTO_LOCK_AFTER.as_secs() / 60
+            ))
+            .style(Color::Green),
+            false => Span::from("off, set a passphrase to turn it on").style(Color::Yellow),
+        };
+        let seed_backup = match state.seed_backup {
+            SeedBackup::BackedUp => Span::from("written down").style(Color::Green),
+            _ => Span::from("not backed up").style(Color::Red),
+        };
+
+        let profile = Span::from(state.profile.clone().unwrap_or_default()).style(Color::Green);
+
+        let lines = vec![
+            Line::from(vec![
+                Span::from("[o] ").style(Color::Blue),
+                Span::from("Profile       "),
+                profile,
+            ]),
+            Line::from(vec![
+                Span::from("[p] ").style(Color::Blue),
+                Span::from("Passphrase    "),
+                passphrase,
+            ]),
+            Line::from(vec![
+                Span::from("[l] ").style(Color::Blue),
+                Span::from("Auto-lock     "),
+                auto_lock,
+            ]),
+            Line::from(vec![
+                Span::from("[w] ").style(Color::Blue),
+                Span::from("Seed backup   "),
+                seed_backup,
+            ]),
+        ];
+
+        frame.render_widget(
+            Paragraph::new(lines).block(block.padding(Padding::uniform(1))),
+            frame.area(),
+        );
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        _tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::Settings {
+            return Ok(());
+        }
+
+        match event.code {
+            KeyCode::Char('o') => {
+                state.profile_action = ProfileAction::Idle;
+                state.navigate(Screen::Profiles);
+            }
+            KeyCode::Char('p') => {
+                state.passphrase = PassphraseChange::Idle;
+                state.navigate(Screen::Passphrase);
+            }
+            KeyCode::Char('l') if state.encrypted => {
+                state.lock_screen();
+            }
+            // Only a passphrase can unlock the app, so auto-lock is turned on by setting one
+            KeyCode::Char('l') => {
+                state.passphrase = PassphraseChange::Idle;
+                state.navigate(Screen::Passphrase);
+            }
+            KeyCode::Char('w') => {
+                state.seed = SeedReveal::Hidden;
+                state.navigate(Screen::Seed);
+            }
+            KeyCode::Esc => {
+                state.navigate(Screen::Splash);
+            }
+            _ => {}
+        }
+
+        Ok(())
     }
 }
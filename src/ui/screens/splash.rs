@@ -54,6 +54,12 @@ impl Component for SplashScreen {
             Span::from("] Join a Mint"),
         ]));
 
+        lines.push(Line::from(vec![
+            Span::from("- ["),
+            Span::from("r").style(Style::default().fg(Color::Yellow)),
+            Span::from("] Restore    "),
+        ]));
+
         // TODO: show wallets if any
         if false {
             lines.push(Line::from(vec![
@@ -65,7 +71,7 @@ impl Component for SplashScreen {
 
         lines.push(Line::from(vec![
             Span::from("- ["),
-            Span::from("w").style(Style::default().fg(Color::Yellow)),
+            Span::from("s").style(Style::default().fg(Color::Yellow)),
             Span::from("] Settings   "),
         ]));
 
@@ -101,10 +107,18 @@ impl Component for SplashScreen {
             state.navigate(Screen::Join);
         }
 
+        if let KeyCode::Char('r') = event.code {
+            state.navigate(Screen::Restore);
+        }
+
         if let KeyCode::Char('w') = event.code {
             state.navigate(Screen::Wallets);
         }
 
+        if let KeyCode::Char('s') = event.code {
+            state.navigate(Screen::Settings);
+        }
+
         if let KeyCode::Char('t') = event.code {
             state.navigate(Screen::Tutorial);
         }
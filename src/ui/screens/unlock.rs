@@ -0,0 +1,84 @@
+use crate::state::{Screen, Unlock};
+use crate::ui::prelude::*;
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+/// Asks for the passphrase the seed is encrypted with
+pub struct UnlockScreen {
+    passphrase: TextInput,
+}
+
+impl UnlockScreen {
+    pub fn new() -> Self {
+        Self {
+            passphrase: TextInput::masked(),
+        }
+    }
+}
+
+impl Component for UnlockScreen {
+    fn draw(&self, frame: &mut Frame, state: &AppStateMutex) {
+        let state = state.lock().unwrap();
+
+        if state.screen != Screen::Unlock {
+            return;
+        }
+
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(" Unlock ")
+            .title_bottom(" [enter] unlock  CTRL+C to exit ")
+            .title_alignment(Alignment::Center);
+        let area = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        let [input, status] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+        let unlocking = state.unlock == Unlock::Unlocking;
+        self.passphrase.draw(frame, input, "Passphrase", !unlocking);
+
+        let line = match &state.unlock {
+            Unlock::Unlocking => Line::from("Unlocking..."),
+            Unlock::Failed(error) => Line::from(error.as_str()).style(Color::Red),
+            _ if state.locked_from.is_some() => {
+                Line::from("Wallet locked, enter your passphrase to continue")
+            }
+            _ => Line::from("Your seed is encrypted, enter your passphrase to unlock it"),
+        };
+
+        frame.render_widget(
+            Paragraph::new(line)
+                .wrap(Wrap { trim: false })
+                .block(Block::new().padding(Padding::uniform(1))),
+            status,
+        );
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::Unlock || state.unlock == Unlock::Unlocking {
+            return Ok(());
+        }
+
+        match event.code {
+            KeyCode::Enter if !self.passphrase.value().is_empty() => {
+                tx.send(Message::Unlock(self.passphrase.value().to_string()))
+                    .ok();
+                self.passphrase.clear();
+                state.unlock = Unlock::Unlocking;
+            }
+            _ => {
+                self.passphrase.on_key_event(event);
+            }
+        }
+
+        Ok(())
+    }
+}
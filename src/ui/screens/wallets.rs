@@ -1,8 +1,26 @@
-use crate::state::Screen;
+use crate::state::{
+    Deposit, EcashReceive, EcashSend, FederationHealth, FederationView, GatewayList, History,
+    RecoveryStatus, Screen, SeedBackup, SeedReveal,
+};
 use crate::ui::prelude::*;
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
+/// Key bindings for the selected federation, shown beside the list
+const ACTIONS: &[(&str, &str)] = &[
+    ("enter", "details"),
+    ("r", "receive lightning"),
+    ("s", "send lightning"),
+    ("e", "send ecash"),
+    ("p", "receive ecash"),
+    ("u", "unclaimed ecash"),
+    ("l", "lnurl-withdraw"),
+    ("d", "deposit on-chain"),
+    ("o", "withdraw on-chain"),
+    ("g", "gateways"),
+    ("b", "back up now"),
+];
+
 pub struct WalletsScreen {}
 
 impl Component for WalletsScreen {
@@ -13,8 +31,227 @@ impl Component for WalletsScreen {
             return;
         }
 
-        let paragraph = ratatui::widgets::Paragraph::new("Wallets");
-        let block = Block::bordered().border_style(Style::new().cyan().on_white().bold().italic());
-        frame.render_widget(paragraph.clone().block(block), frame.area());
+        let block = Block::bordered()
+            .border_style(Style::new().blue())
+            .title(" Wallets ")
+            .title(
+                Line::from(format!(" Total {} ", format::sats(state.total_balance())))
+                    .right_aligned(),
+            )
+            .title_bottom(" [↑↓] select  [h] history  [j] join  [esc] back ")
+            .title_alignment(Alignment::Center);
+
+        let area = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        // Stays up until the user has shown they wrote the seed down
+        let area = if state.seed_backup == SeedBackup::NotBackedUp {
+            let [banner, area] =
+                Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area);
+
+            frame.render_widget(
+                Paragraph::new(" Your seed isn't backed up, press [w] to write it down ")
+                    .alignment(Alignment::Center)
+                    .style(Style::new().black().on_yellow()),
+                banner,
+            );
+            area
+        } else {
+            area
+        };
+
+        if state.federations.is_empty() {
+            let paragraph = Paragraph::new("No federations joined yet, press [j] to join one")
+                .alignment(Alignment::Center)
+                .block(Block::new().padding(Padding::vertical(1)));
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let items: Vec<ListItem> = state
+            .federations
+            .iter()
+            .map(|federation| {
+                let name = federation
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| federation.id.to_string());
+
+                // Guardian health at a glance, details are on the federation screen
+                let health = match state.federation_health(federation.id) {
+                    Some(FederationHealth::Online) => Span::from("● ").style(Color::Green),
+                    Some(FederationHealth::Degraded { online, total }) => {
+                        Span::from(format!("● {online}/{total} ")).style(Color::Yellow)
+                    }
+                    Some(FederationHealth::Offline { online, total }) => {
+                        Span::from(format!("● {online}/{total} ")).style(Color::Red)
+                    }
+                    None => Span::from("○ ").style(Color::DarkGray),
+                };
+
+                let mut line = Line::from(vec![
+                    health,
+                    Span::from(name),
+                    Span::from("  "),
+                    Span::from(format::sats(federation.balance)).style(Color::Yellow),
+                ]);
+
+                if state.backup_is_stale(federation.id) {
+                    line.push_span(Span::from("  backup stale").style(Color::Red));
+                }
+
+                // The balance is incomplete until the seed's ecash is rebuilt
+                if let Some(recovery) = state
+                    .recoveries
+                    .iter()
+                    .find(|r| r.federation_id == federation.id)
+                    && matches!(recovery.status, RecoveryStatus::Recovering { .. })
+                {
+                    let percent = recovery
+                        .status
+                        .percent()
+                        .map(|percent| format!(" {percent}%"))
+                        .unwrap_or_default();
+
+                    line.push_span(
+                        Span::from(format!("  recovering{percent}")).style(Color::DarkGray),
+                    );
+                }
+
+                ListItem::new(line)
+            })
+            .collect();
+
+        let selected = state
+            .federations
+            .iter()
+            .position(|f| Some(f.id) == state.selected_federation);
+        let mut list_state = ListState::default().with_selected(selected);
+
+        let [list_area, actions_area] =
+            Layout::horizontal([Constraint::Min(0), Constraint::Length(26)]).areas(area);
+
+        let list = List::new(items)
+            .block(Block::new().padding(Padding::horizontal(1)))
+            .highlight_symbol("> ")
+            .highlight_style(Style::new().bold());
+        frame.render_stateful_widget(list, list_area, &mut list_state);
+
+        let actions: Vec<Line> = ACTIONS
+            .iter()
+            .map(|(key, action)| {
+                Line::from(vec![
+                    Span::from(format!("[{key}] ")).style(Color::Blue),
+                    Span::from(*action),
+                ])
+            })
+            .collect();
+        frame.render_widget(
+            Paragraph::new(actions).block(
+                Block::new()
+                    .borders(Borders::LEFT)
+                    .padding(Padding::horizontal(1)),
+            ),
+            actions_area,
+        );
+    }
+
+    fn on_key_event(
+        &mut self,
+        event: KeyEvent,
+        state: &AppStateMutex,
+        tx: TxSender,
+    ) -> anyhow::Result<()> {
+        let mut state = state.lock().unwrap();
+
+        if state.screen != Screen::Wallets {
+            return Ok(());
+        }
+
+        let selected = state
+            .federations
+            .iter()
+            .position(|f| Some(f.id) == state.selected_federation);
+
+        match event.code {
+            KeyCode::Up => {
+                let index = selected.map_or(0, |i| i.saturating_sub(1));
+                state.selected_federation = state.federations.get(index).map(|f| f.id);
+            }
+            KeyCode::Down => {
+                let index = selected.map_or(0, |i| i + 1);
+                if let Some(federation) = state.federations.get(index) {
+                    state.selected_federation = Some(federation.id);
+                }
+            }
+            KeyCode::Enter => {
+                if let Some(federation_id) = state.selected_federation {
+                    state.federation = FederationView::Loading;
+                    tx.send(Message::LoadFederation(federation_id)).ok();
+                    state.navigate(Screen::Federation);
+                }
+            }
+            KeyCode::Char('r') if selected.is_some() => {
+                state.navigate(Screen::Receive);
+            }
+            KeyCode::Char('s') if selected.is_some() => {
+                state.navigate(Screen::Send);
+            }
+            KeyCode::Char('e') if selected.is_some() => {
+                state.ecash_send = EcashSend::Idle;
+                state.navigate(Screen::SendEcash);
+            }
+            KeyCode::Char('p') => {
+                state.ecash_receive = EcashReceive::Idle;
+                state.navigate(Screen::ReceiveEcash);
+            }
+            KeyCode::Char('u') => {
+                state.navigate(Screen::PendingEcash);
+            }
+            KeyCode::Char('l') if selected.is_some() => {
+                state.navigate(Screen::LnurlWithdraw);
+            }
+            KeyCode::Char('o') if selected.is_some() => {
+                state.navigate(Screen::PegOut);
+            }
+            KeyCode::Char('d') => {
+                if let Some(federation_id) = state.selected_federation {
+                    state.deposit = Deposit::Allocating;
+                    tx.send(Message::DepositAddress(federation_id)).ok();
+                    state.navigate(Screen::Deposit);
+                }
+            }
+            KeyCode::Char('g') => {
+                if let Some(federation_id) = state.selected_federation {
+                    state.gateways = GatewayList::Loading;
+                    tx.send(Message::ListGateways(federation_id)).ok();
+                    state.navigate(Screen::Gateways);
+                }
+            }
+            KeyCode::Char('b') => {
+                if let Some(federation_id) = state.selected_federation {
+                    state.backups.entry(federation_id).or_default().running = true;
+                    tx.send(Message::BackupNow(federation_id)).ok();
+                }
+            }
+            KeyCode::Char('w') => {
+                state.seed = SeedReveal::Hidden;
+                state.navigate(Screen::Seed);
+            }
+            KeyCode::Char('h') => {
+                state.history = History::Loading;
+                tx.send(Message::LoadHistory).ok();
+                state.navigate(Screen::History);
+            }
+            KeyCode::Char('j') => {
+                state.navigate(Screen::Join);
+            }
+            KeyCode::Esc => {
+                state.navigate(Screen::Splash);
+            }
+            _ => {}
+        }
+
+        Ok(())
     }
 }
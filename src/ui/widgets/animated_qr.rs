@@ -0,0 +1,201 @@
+use super::Qr;
+use anyhow::{Result, anyhow};
+use ratatui::prelude::*;
+
+/// Bytes of payload per frame, small enough for a phone camera to pick up quickly
+const FRAGMENT_LEN: usize = 120;
+
+/// How many QR frames are shown per second
+const FRAMES_PER_SECOND: u64 = 5;
+
+/// Frames advance every this many ticks, ticks happen once per rendered frame
+const TICKS_PER_FRAME: u64 = match crate::FRAME_RATE / FRAMES_PER_SECOND {
+    0 => 1,
+    ticks => ticks,
+};
+
+/// A QR code cycling through fountain coded BC-UR frames, for payloads too big for one code
+///
+/// The fountain code keeps producing new frames that mix fragments together, so a scanner
+/// that missed some frames can still finish without waiting for the exact ones it lacks.
+pub struct AnimatedQr {
+    data: String,
+    encoder: ur::Encoder<'static>,
+    current: Option<Qr>,
+    ticks: u64,
+}
+
+impl AnimatedQr {
+    pub fn new(data: &str) -> Result<Self> {
+        let encoder = ur::Encoder::bytes(data.as_bytes(), FRAGMENT_LEN)
+            .map_err(|e| anyhow!("failed to encode QR frames: {e}"))?;
+
+        let mut animated = Self {
+            data: data.to_string(),
+            encoder,
+            current: None,
+            ticks: 0,
+        };
+        animated.next_frame()?;
+
+        Ok(animated)
+    }
+
+    /// The data being shown
+    pub fn data(&self) -> &str {
+        &self.data
+    }
+
+    fn next_frame(&mut self) -> Result<()> {
+        let part = self
+            .encoder
+            .next_part()
+            .map_err(|e| anyhow!("failed to encode QR frame: {e}"))?;
+
+        // UR is case insensitive, and uppercase encodes to a smaller code
+        self.current = Some(Qr::new(&part.to_uppercase())?);
+
+        Ok(())
+    }
+
+    /// Advances the animation, called once per rendered frame
+    pub fn tick(&mut self) -> Result<()> {
+        self.ticks += 1;
+
+        if self.ticks % TICKS_PER_FRAME == 0 {
+            self.next_frame()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Widget for &AnimatedQr {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if let Some(qr) = &self.current {
+            qr.render(area, buf);
+        }
+    }
+}
+
+/// Reassembles data from the frames of an `AnimatedQr`, pasted back in any order
+#[derive(Default)]
+pub struct FrameAssembler {
+    decoder: ur::Decoder,
+    frames: usize,
+    needed: Option<usize>,
+}
+
+impl FrameAssembler {
+    /// Whether `text` is a BC-UR frame rather than plain data
+    pub fn is_frame(text: &str) -> bool {
+        text.trim().to_lowercase().starts_with("ur:")
+    }
+
+    pub fn receive(&mut self, frame: &str) -> Result<()> {
+        let frame = frame.trim().to_lowercase();
+
+        self.decoder
+            .receive(&frame)
+            .map_err(|e| anyhow!("invalid QR frame: {e}"))?;
+        self.frames += 1;
+
+        // Multi-part frames look like ur:bytes/<seq>-<total>/<payload>
+        self.needed = frame
+            .split('/')
+            .nth(1)
+            .and_then(|sequence| sequence.split_once('-'))
+            .and_then(|(_, total)| total.parse().ok());
+
+        Ok(())
+    }
+
+    /// Frames received so far
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// The fewest frames that could complete the data, once known
+    pub fn needed(&self) -> Option<usize> {
+        self.needed
+    }
+
+    /// The reassembled data, once enough frames have arrived
+    pub fn message(&self) -> Result<Option<String>> {
+        if !self.decoder.complete() {
+            return Ok(None);
+        }
+
+        let message = self
+            .decoder
+            .message()
+            .map_err(|e| anyhow!("failed to reassemble QR frames: {e}"))?;
+
+        message
+            .map(String::from_utf8)
+            .transpose()
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Long enough to need many frames, like the notes of a sizable ecash spend
+    fn payload() -> String {
+        (0..2000)
+            .map(|i| char::from(b'a' + (i % 26) as u8))
+            .collect()
+    }
+
+    #[test]
+    fn frames_round_trip() {
+        let data = payload();
+        let mut animated = AnimatedQr::new(&data).unwrap();
+        let mut assembler = FrameAssembler::default();
+
+        // Scanners see the uppercase form shown in the code
+        while assembler.message().unwrap().is_none() {
+            let part = animated.encoder.next_part().unwrap().to_uppercase();
+
+            assert!(FrameAssembler::is_frame(&part));
+            assembler.receive(&part).unwrap();
+            assert!(assembler.frames() < 1000, "frames never completed the data");
+        }
+
+        assert_eq!(assembler.message().unwrap(), Some(data));
+        assert!(assembler.needed().is_some());
+    }
+
+    #[test]
+    fn missed_frames_are_made_up_for() {
+        let data = payload();
+        let mut animated = AnimatedQr::new(&data).unwrap();
+        let mut assembler = FrameAssembler::default();
+        let mut sent = 0;
+
+        while assembler.message().unwrap().is_none() {
+            let part = animated.encoder.next_part().unwrap();
+            sent += 1;
+
+            // The camera only catches every third frame
+            if sent % 3 == 0 {
+                assembler.receive(&part).unwrap();
+            }
+
+            assert!(sent < 3000, "frames never completed the data");
+        }
+
+        assert_eq!(assembler.message().unwrap(), Some(data));
+    }
+
+    #[test]
+    fn rejects_garbage_frames() {
+        let mut assembler = FrameAssembler::default();
+
+        assert!(!FrameAssembler::is_frame("AgEEsmmXsw"));
+        assert!(assembler.receive("ur:bytes/not-a-frame").is_err());
+        assert_eq!(assembler.message().unwrap(), None);
+    }
+}
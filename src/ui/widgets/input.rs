@@ -0,0 +1,84 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+/// A single line text field
+#[derive(Debug, Default, Clone)]
+pub struct TextInput {
+    value: String,
+    /// Draws each character as a dot, for passphrases
+    masked: bool,
+}
+
+impl TextInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A field pre-filled with `value`, e.g. for editing something that already exists
+    pub fn with_value(value: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            masked: false,
+        }
+    }
+
+    pub fn masked() -> Self {
+        Self {
+            value: String::new(),
+            masked: true,
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn clear(&mut self) {
+        self.value.clear();
+    }
+
+    /// Applies a key press to the field, returns whether it was consumed
+    pub fn on_key_event(&mut self, event: KeyEvent) -> bool {
+        if event.modifiers.contains(KeyModifiers::CONTROL) {
+            return false;
+        }
+
+        match event.code {
+            KeyCode::Char(c) => {
+                self.value.push(c);
+                true
+            }
+            KeyCode::Backspace => {
+                self.value.pop();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Draws the field, scrolled so the end of long values (e.g. pasted invoices) stays visible
+    pub fn draw(&self, frame: &mut Frame, area: Rect, title: &str, focused: bool) {
+        let style = if focused {
+            Style::new().yellow()
+        } else {
+            Style::new().blue()
+        };
+
+        let width = area.width.saturating_sub(3) as usize;
+        let len = self.value.chars().count();
+        let visible: String = match self.masked {
+            true => "•".repeat(len.min(width)),
+            false => self.value.chars().skip(len.saturating_sub(width)).collect(),
+        };
+        let cursor = if focused { "▏" } else { "" };
+
+        let block = Block::bordered()
+            .border_style(style)
+            .title(format!(" {title} "));
+        frame.render_widget(
+            Paragraph::new(format!("{visible}{cursor}")).block(block),
+            area,
+        );
+    }
+}
@@ -0,0 +1,7 @@
+mod animated_qr;
+mod input;
+mod qr;
+
+pub use animated_qr::{AnimatedQr, FrameAssembler};
+pub use input::TextInput;
+pub use qr::Qr;
@@ -0,0 +1,112 @@
+use anyhow::Result;
+use qrcode::{EcLevel, QrCode};
+use ratatui::prelude::*;
+use ratatui::widgets::*;
+
+/// Light modules around the code that scanners need to find its edges
+const QUIET_ZONE: usize = 2;
+
+/// A QR code drawn with half blocks, each cell holding two modules stacked vertically
+#[derive(Debug, Clone)]
+pub struct Qr {
+    data: String,
+    modules: Vec<bool>,
+    width: usize,
+}
+
+impl Qr {
+    pub fn new(data: &str) -> Result<Self> {
+        let code = QrCode::with_error_correction_level(data.as_bytes(), EcLevel::L)?;
+
+        Ok(Self {
+            data: data.to_string(),
+            width: code.width(),
+            modules: code
+                .to_colors()
+                .into_iter()
+                .map(|module| module == qrcode::Color::Dark)
+                .collect(),
+        })
+    }
+
+    /// The data encoded in the code
+    pub fn data(&self) -> &str {
+        &self.data
+    }
+
+    /// Keeps `cached` showing `data`, encoding a new code only when the data changes
+    ///
+    /// Encoding is too slow to repeat on every frame, so screens cache their code from `on_tick`
+    pub fn cache(cached: &mut Option<Qr>, data: Option<&str>) {
+        match data {
+            Some(data) if cached.as_ref().map(Qr::data) != Some(data) => {
+                *cached = Qr::new(data).ok();
+            }
+            Some(_) => {}
+            None => *cached = None,
+        }
+    }
+
+    /// Draws the code with `caption` wrapped underneath, for copying when scanning isn't an option
+    pub fn draw_with_caption(&self, frame: &mut Frame, area: Rect, caption: &str) {
+        let width = area.width.max(1) as usize;
+        let height = caption.chars().count().div_ceil(width) as u16;
+
+        let [code, text] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(height)]).areas(area);
+
+        frame.render_widget(self, code);
+        frame.render_widget(
+            Paragraph::new(caption)
+                .style(Color::DarkGray)
+                .wrap(Wrap { trim: false }),
+            text,
+        );
+    }
+
+    /// Whether the module at `x`, `y` is dark, counting the quiet zone
+    fn dark(&self, x: usize, y: usize) -> bool {
+        let (Some(x), Some(y)) = (x.checked_sub(QUIET_ZONE), y.checked_sub(QUIET_ZONE)) else {
+            return false;
+        };
+
+        x < self.width && y < self.width && self.modules[y * self.width + x]
+    }
+}
+
+impl Widget for &Qr {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let size = self.width + QUIET_ZONE * 2;
+
+        // A cell is one module wide and two tall, which keeps modules roughly square
+        let scale = (area.width as usize / size).min(area.height as usize * 2 / size);
+
+        if scale == 0 {
+            Paragraph::new("Terminal too small to show the QR code")
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: true })
+                .render(area, buf);
+            return;
+        }
+
+        let columns = size * scale;
+        let rows = columns.div_ceil(2);
+        let left = area.x + (area.width - columns as u16) / 2;
+        let top = area.y + (area.height - rows as u16) / 2;
+
+        let color = |dark: bool| if dark { Color::Black } else { Color::White };
+
+        for row in 0..rows {
+            for column in 0..columns {
+                let upper = self.dark(column / scale, row * 2 / scale);
+                let lower = self.dark(column / scale, (row * 2 + 1) / scale);
+
+                if let Some(cell) = buf.cell_mut((left + column as u16, top + row as u16)) {
+                    cell.set_symbol("▀")
+                        .set_fg(color(upper))
+                        .set_bg(color(lower));
+                }
+            }
+        }
+    }
+}